@@ -37,7 +37,7 @@ fn main() {
         app.add_plugins(RapierDebugRenderPlugin::default());
         // To use Tnua with bevy_rapier2d, you need the `TnuaRapier2dPlugin` plugin from
         // bevy-tnua-rapier2d.
-        app.add_plugins(TnuaRapier2dPlugin);
+        app.add_plugins(TnuaRapier2dPlugin::default());
     }
     #[cfg(feature = "xpbd2d")]
     {
@@ -45,15 +45,15 @@ fn main() {
         app.add_plugins(PhysicsDebugPlugin::default());
         // To use Tnua with bevy_xpbd_2d, you need the `TnuaXpbd2dPlugin` plugin from
         // bevy-tnua-xpbd2d.
-        app.add_plugins(TnuaXpbd2dPlugin);
+        app.add_plugins(TnuaXpbd2dPlugin::default());
     }
 
     // This is Tnua's main plugin.
-    app.add_plugins(TnuaControllerPlugin);
+    app.add_plugins(TnuaControllerPlugin::default());
 
     // This plugin supports `TnuaCrouchEnforcer`, which prevents the character from standing up
     // while obstructed by an obstacle.
-    app.add_plugins(TnuaCrouchEnforcerPlugin);
+    app.add_plugins(TnuaCrouchEnforcerPlugin::default());
 
     app.add_plugins(tnua_demos_crate::ui::DemoUi::<
         CharacterMotionConfigForPlatformerDemo,
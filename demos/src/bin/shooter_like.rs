@@ -43,22 +43,22 @@ fn main() {
         app.add_plugins(RapierPhysicsPlugin::<NoUserData>::default());
         // To use Tnua with bevy_rapier3d, you need the `TnuaRapier3dPlugin` plugin from
         // bevy-tnua-rapier3d.
-        app.add_plugins(TnuaRapier3dPlugin);
+        app.add_plugins(TnuaRapier3dPlugin::default());
     }
     #[cfg(feature = "xpbd3d")]
     {
         app.add_plugins(PhysicsPlugins::default());
         // To use Tnua with bevy_xpbd_3d, you need the `TnuaXpbd3dPlugin` plugin from
         // bevy-tnua-xpbd3d.
-        app.add_plugins(TnuaXpbd3dPlugin);
+        app.add_plugins(TnuaXpbd3dPlugin::default());
     }
 
     // This is Tnua's main plugin.
-    app.add_plugins(TnuaControllerPlugin);
+    app.add_plugins(TnuaControllerPlugin::default());
 
     // This plugin supports `TnuaCrouchEnforcer`, which prevents the character from standing up
     // while obstructed by an obstacle.
-    app.add_plugins(TnuaCrouchEnforcerPlugin);
+    app.add_plugins(TnuaCrouchEnforcerPlugin::default());
 
     app.add_plugins(tnua_demos_crate::ui::DemoUi::<
         CharacterMotionConfigForPlatformerDemo,
@@ -17,8 +17,8 @@ fn main() {
             PhysicsPlugins::default(),
             // We need both Tnua's main controller plugin, and the plugin to connect to the physics
             // backend (in this case XBPD-3D)
-            TnuaControllerPlugin,
-            TnuaXpbd3dPlugin,
+            TnuaControllerPlugin::default(),
+            TnuaXpbd3dPlugin::default(),
         ))
         .add_systems(
             Startup,
@@ -0,0 +1,665 @@
+//! # Headless Mock Physics Backend for bevy-tnua
+//!
+//! This crate is a minimal physics integration for Tnua, intended for testing bases and actions
+//! without a real physics engine (and without rendering or windowing). It only implements what
+//! Tnua needs from a backend:
+//!
+//! * Simple kinematics - velocity, gravity and the accelerations from the motor are integrated
+//!   into the `Transform`. There is no collision resolution - the character is kept above the
+//!   ground by Tnua's own floating mechanism.
+//! * Ray casts against [`TnuaMockCollider`]s, which can be half-spaces (planes) or oriented
+//!   cuboids. Slopes can be made by rotating them, and moving platforms can be made by giving them
+//!   a [`TnuaMockRigidBody::Kinematic`] body with a [`TnuaMockVelocity`].
+//!
+//! Since it is meant to be used with `MinimalPlugins`, which does not propagate transforms, this
+//! backend works with `Transform` instead of `GlobalTransform`. Subservient sensors (like the one
+//! created by `TnuaCrouchEnforcer`) are positioned relative to the `Transform` of their owner
+//! entity. Scale is ignored.
+//!
+//! To use it:
+//!
+//! * Add [`TnuaMockPlugin`] to the Bevy app.
+//! * Add [`TnuaMockRigidBody::Dynamic`] and [`TnuaMockIOBundle`] to each character entity
+//!   controlled by Tnua.
+//! * Add [`TnuaMockCollider`] to the entities the characters should be able to stand on.
+//!   Entities that also have `TnuaWaterVolume` are only detected by `TnuaWaterSensor`.
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use bevy::prelude::*;
+//! # use bevy::time::TimeUpdateStrategy;
+//! # use bevy_tnua::prelude::*;
+//! # use bevy_tnua_mock::*;
+//! let mut app = App::new();
+//! app.add_plugins(MinimalPlugins);
+//! app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+//!     1.0 / 60.0,
+//! )));
+//! app.add_plugins((TnuaControllerPlugin::default(), TnuaMockPlugin::default()));
+//!
+//! app.world.spawn((Transform::default(), TnuaMockCollider::Plane));
+//!
+//! let mut controller = TnuaController::default();
+//! controller.basis(TnuaBuiltinWalk {
+//!     float_height: 1.5,
+//!     ..Default::default()
+//! });
+//! let character = app
+//!     .world
+//!     .spawn((
+//!         Transform::from_xyz(0.0, 3.0, 0.0),
+//!         TnuaMockRigidBody::Dynamic,
+//!         TnuaMockIOBundle::default(),
+//!         TnuaControllerBundle {
+//!             controller,
+//!             ..Default::default()
+//!         },
+//!     ))
+//!     .id();
+//!
+//! for _ in 0..300 {
+//!     app.update();
+//! }
+//!
+//! let controller = app.world.get::<TnuaController>(character).unwrap();
+//! assert!(!controller.is_airborne().unwrap());
+//! let transform = app.world.get::<Transform>(character).unwrap();
+//! assert!((transform.translation.y - 1.5).abs() < 0.1);
+//! ```
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
+use bevy::prelude::*;
+
+use bevy_tnua_physics_integration_layer::data_for_backends::TnuaGhostPlatform;
+use bevy_tnua_physics_integration_layer::data_for_backends::TnuaGhostSensor;
+use bevy_tnua_physics_integration_layer::data_for_backends::TnuaToggle;
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaLedgeSensor, TnuaLedgeSensorOutput, TnuaStepSensor, TnuaStepSensorOutput,
+};
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaMotor, TnuaProximitySensor, TnuaProximitySensorOutput, TnuaRigidBodyTracker,
+};
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaWallSensor, TnuaWallSensorOutput,
+};
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaWaterSensor, TnuaWaterSensorOutput, TnuaWaterVolume,
+};
+use bevy_tnua_physics_integration_layer::math::*;
+use bevy_tnua_physics_integration_layer::subservient_sensors::TnuaSubservientSensor;
+use bevy_tnua_physics_integration_layer::TnuaPipelineStages;
+use bevy_tnua_physics_integration_layer::TnuaSystemSet;
+
+/// Add this plugin to use the mock physics backend.
+///
+/// This plugin should be used in addition to `TnuaControllerPlugin`, and both plugins must use
+/// the same schedule.
+pub struct TnuaMockPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl TnuaMockPlugin {
+    /// Create the plugin, running its systems in the given schedule.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
+impl Default for TnuaMockPlugin {
+    fn default() -> Self {
+        Self::new(Update)
+    }
+}
+
+impl Plugin for TnuaMockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TnuaMockPhysicsConfig>();
+        app.configure_sets(
+            self.schedule,
+            TnuaSystemSet.run_if(|config: Res<TnuaMockPhysicsConfig>| config.active),
+        );
+        app.add_systems(
+            self.schedule,
+            (
+                update_rigid_body_trackers_system,
+                update_proximity_sensors_system,
+                update_water_sensors_system,
+                update_wall_sensors_system,
+                update_ledge_sensors_system,
+                update_step_sensors_system,
+            )
+                .in_set(TnuaPipelineStages::Sensors),
+        );
+        app.add_systems(
+            self.schedule,
+            (apply_motors_system, step_simulation_system)
+                .chain()
+                .in_set(TnuaPipelineStages::Motors),
+        );
+    }
+}
+
+/// Global settings of the mock physics simulation.
+#[derive(Resource, Debug, Clone)]
+pub struct TnuaMockPhysicsConfig {
+    /// When `false`, the simulation is paused and Tnua's systems do not run.
+    pub active: bool,
+    /// The gravity applied to all [`TnuaMockRigidBody::Dynamic`] bodies.
+    pub gravity: Vector3,
+}
+
+impl Default for TnuaMockPhysicsConfig {
+    fn default() -> Self {
+        Self {
+            active: true,
+            gravity: Vector3::NEG_Y * 9.81,
+        }
+    }
+}
+
+/// A body that the mock backend moves according to its [`TnuaMockVelocity`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TnuaMockRigidBody {
+    /// Affected by gravity and by [`TnuaMockExternalAcceleration`].
+    Dynamic,
+    /// Only moves according to its velocity. Use this for moving platforms.
+    Kinematic,
+}
+
+/// The velocity of a [`TnuaMockRigidBody`].
+#[derive(Component, Debug, Clone, Default)]
+pub struct TnuaMockVelocity {
+    pub linvel: Vector3,
+    /// Angular velocity as the rotation axis multiplied by the rotation speed in radians per
+    /// second.
+    pub angvel: Vector3,
+}
+
+/// Acceleration applied to a [`TnuaMockRigidBody::Dynamic`] body in addition to gravity.
+///
+/// The mock backend ignores mass, so this is the equivalent of an external force.
+#[derive(Component, Debug, Clone, Default)]
+pub struct TnuaMockExternalAcceleration {
+    pub linear: Vector3,
+    pub angular: Vector3,
+}
+
+/// Mock-backend-specific components required for Tnua to work.
+#[derive(Bundle, Default)]
+pub struct TnuaMockIOBundle {
+    pub velocity: TnuaMockVelocity,
+    pub external_acceleration: TnuaMockExternalAcceleration,
+}
+
+/// A shape that [`TnuaProximitySensor`]s can detect.
+///
+/// The shape is positioned and oriented by the entity's `Transform`.
+#[derive(Component, Debug, Clone)]
+pub enum TnuaMockCollider {
+    /// A solid half-space, with its surface passing through the translation and facing the
+    /// rotated Y axis.
+    Plane,
+    /// A box centered on the translation.
+    Cuboid { half_extents: Vector3 },
+}
+
+struct CastResult {
+    proximity: Float,
+    normal: Direction3d,
+}
+
+impl TnuaMockCollider {
+    fn cast_ray(
+        &self,
+        transform: &Transform,
+        origin: Vector3,
+        direction: Direction3d,
+        max_proximity: Float,
+    ) -> Option<CastResult> {
+        let center = transform.translation.adjust_precision();
+        let rotation = transform.rotation.adjust_precision();
+        let direction_vec = direction.adjust_precision();
+        let result = match self {
+            TnuaMockCollider::Plane => {
+                let normal = rotation * Vector3::Y;
+                let distance = (origin - center).dot(normal);
+                if distance <= 0.0 {
+                    // The origin is inside the solid half-space
+                    CastResult {
+                        proximity: 0.0,
+                        normal: Direction3d::new(normal.f32()).ok()?,
+                    }
+                } else {
+                    let approach_speed = -direction_vec.dot(normal);
+                    if approach_speed <= 0.0 {
+                        return None;
+                    }
+                    CastResult {
+                        proximity: distance / approach_speed,
+                        normal: Direction3d::new(normal.f32()).ok()?,
+                    }
+                }
+            }
+            TnuaMockCollider::Cuboid { half_extents } => {
+                let inverse_rotation = rotation.inverse();
+                let local_origin = inverse_rotation * (origin - center);
+                let local_direction = inverse_rotation * direction_vec;
+                if local_origin.abs().cmple(*half_extents).all() {
+                    CastResult {
+                        proximity: 0.0,
+                        normal: -direction,
+                    }
+                } else {
+                    let mut entry = Float::NEG_INFINITY;
+                    let mut exit = Float::INFINITY;
+                    let mut entry_normal = Vector3::ZERO;
+                    for axis in 0..3 {
+                        let (origin, direction, extent) = (
+                            local_origin[axis],
+                            local_direction[axis],
+                            half_extents[axis],
+                        );
+                        if direction == 0.0 {
+                            if extent < origin.abs() {
+                                return None;
+                            }
+                            continue;
+                        }
+                        let t1 = (-extent - origin) / direction;
+                        let t2 = (extent - origin) / direction;
+                        let (near, far) = if t1 < t2 { (t1, t2) } else { (t2, t1) };
+                        if entry < near {
+                            entry = near;
+                            entry_normal = Vector3::ZERO;
+                            entry_normal[axis] = -direction.signum();
+                        }
+                        exit = exit.min(far);
+                    }
+                    if exit < entry || entry < 0.0 {
+                        return None;
+                    }
+                    CastResult {
+                        proximity: entry,
+                        normal: Direction3d::new((rotation * entry_normal).f32()).ok()?,
+                    }
+                }
+            }
+        };
+        (result.proximity <= max_proximity).then_some(result)
+    }
+}
+
+fn update_rigid_body_trackers_system(
+    config: Res<TnuaMockPhysicsConfig>,
+    mut query: Query<(
+        &Transform,
+        &TnuaMockVelocity,
+        &mut TnuaRigidBodyTracker,
+        Option<&TnuaToggle>,
+    )>,
+) {
+    for (transform, velocity, mut tracker, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        *tracker = TnuaRigidBodyTracker {
+            translation: transform.translation.adjust_precision(),
+            rotation: transform.rotation.adjust_precision(),
+            velocity: velocity.linvel,
+            angvel: velocity.angvel,
+            gravity: config.gravity,
+        };
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_proximity_sensors_system(
+    mut query: Query<(
+        Entity,
+        &Transform,
+        &mut TnuaProximitySensor,
+        Option<&mut TnuaGhostSensor>,
+        Option<&TnuaSubservientSensor>,
+        Option<&TnuaToggle>,
+    )>,
+    owners_query: Query<&Transform>,
+    colliders_query: Query<
+        (
+            Entity,
+            &Transform,
+            &TnuaMockCollider,
+            Option<&TnuaMockVelocity>,
+            Has<TnuaGhostPlatform>,
+        ),
+        Without<TnuaWaterVolume>,
+    >,
+) {
+    for (sensor_entity, transform, mut sensor, mut ghost_sensor, subservient, tnua_toggle) in
+        query.iter_mut()
+    {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+
+        let (owner_entity, transform) = if let Some(subservient) = subservient {
+            let Ok(owner_transform) = owners_query.get(subservient.owner_entity) else {
+                continue;
+            };
+            (
+                subservient.owner_entity,
+                owner_transform.mul_transform(*transform),
+            )
+        } else {
+            (sensor_entity, *transform)
+        };
+
+        let cast_origin = transform.translation.adjust_precision()
+            + transform.rotation.adjust_precision() * sensor.cast_origin;
+        let cast_direction = transform.rotation * sensor.cast_direction;
+
+        let mut hits = colliders_query
+            .iter()
+            .filter(|(entity, ..)| *entity != owner_entity)
+            .filter_map(
+                |(entity, collider_transform, collider, velocity, is_ghost)| {
+                    let CastResult { proximity, normal } = collider.cast_ray(
+                        collider_transform,
+                        cast_origin,
+                        cast_direction,
+                        sensor.cast_range,
+                    )?;
+                    let (entity_linvel, entity_angvel) = if let Some(velocity) = velocity {
+                        let relative_point = cast_origin
+                            + proximity * cast_direction.adjust_precision()
+                            - collider_transform.translation.adjust_precision();
+                        (
+                            velocity.linvel + velocity.angvel.cross(relative_point),
+                            velocity.angvel,
+                        )
+                    } else {
+                        (Vector3::ZERO, Vector3::ZERO)
+                    };
+                    Some((
+                        TnuaProximitySensorOutput {
+                            entity,
+                            proximity,
+                            normal,
+                            entity_linvel,
+                            entity_angvel,
+                        },
+                        is_ghost,
+                    ))
+                },
+            )
+            .collect::<Vec<_>>();
+        hits.sort_by(|(a, _), (b, _)| a.proximity.total_cmp(&b.proximity));
+
+        if let Some(ghost_sensor) = ghost_sensor.as_mut() {
+            ghost_sensor.0.clear();
+        }
+        sensor.output = None;
+        for (sensor_output, is_ghost) in hits {
+            if is_ghost {
+                if let Some(ghost_sensor) = ghost_sensor.as_mut() {
+                    ghost_sensor.0.push(sensor_output);
+                }
+            } else {
+                sensor.output = Some(sensor_output);
+                break;
+            }
+        }
+    }
+}
+
+fn update_water_sensors_system(
+    mut query: Query<(
+        Entity,
+        &Transform,
+        &mut TnuaWaterSensor,
+        Option<&TnuaToggle>,
+    )>,
+    water_volumes_query: Query<(Entity, &Transform, &TnuaMockCollider), With<TnuaWaterVolume>>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.translation.adjust_precision()
+            + sensor.cast_range * sensor.up.adjust_precision();
+        sensor.output = water_volumes_query
+            .iter()
+            .filter(|(entity, ..)| *entity != owner_entity)
+            .filter_map(|(entity, collider_transform, collider)| {
+                let CastResult { proximity, .. } = collider.cast_ray(
+                    collider_transform,
+                    cast_origin,
+                    -sensor.up,
+                    sensor.cast_range,
+                )?;
+                Some(TnuaWaterSensorOutput {
+                    entity,
+                    depth: sensor.cast_range - proximity,
+                })
+            })
+            .max_by(|a, b| a.depth.total_cmp(&b.depth));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_wall_sensors_system(
+    mut query: Query<(Entity, &Transform, &mut TnuaWallSensor, Option<&TnuaToggle>)>,
+    colliders_query: Query<
+        (Entity, &Transform, &TnuaMockCollider),
+        (Without<TnuaGhostPlatform>, Without<TnuaWaterVolume>),
+    >,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.translation.adjust_precision()
+            + transform.rotation.adjust_precision() * sensor.cast_origin;
+        sensor.output = colliders_query
+            .iter()
+            .filter(|(entity, ..)| *entity != owner_entity)
+            .filter_map(|(entity, collider_transform, collider)| {
+                let CastResult { proximity, normal } = collider.cast_ray(
+                    collider_transform,
+                    cast_origin,
+                    sensor.cast_direction,
+                    sensor.cast_range,
+                )?;
+                Some(TnuaWallSensorOutput {
+                    entity,
+                    proximity,
+                    normal,
+                })
+            })
+            .min_by(|a, b| a.proximity.total_cmp(&b.proximity));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_ledge_sensors_system(
+    mut query: Query<(
+        Entity,
+        &Transform,
+        &mut TnuaLedgeSensor,
+        Option<&TnuaToggle>,
+    )>,
+    colliders_query: Query<
+        (Entity, &Transform, &TnuaMockCollider),
+        (Without<TnuaGhostPlatform>, Without<TnuaWaterVolume>),
+    >,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.translation.adjust_precision()
+            + transform.rotation.adjust_precision() * sensor.cast_origin;
+        let colliders = || {
+            colliders_query
+                .iter()
+                .filter(|(entity, ..)| *entity != owner_entity)
+        };
+
+        let forward_hit = colliders().any(|(_, collider_transform, collider)| {
+            collider
+                .cast_ray(
+                    collider_transform,
+                    cast_origin,
+                    sensor.cast_direction,
+                    sensor.reach,
+                )
+                .is_some()
+        });
+        if forward_hit {
+            sensor.output = None;
+            continue;
+        }
+
+        let up = sensor.up;
+        let probe_origin = cast_origin + sensor.reach * sensor.cast_direction.adjust_precision();
+        sensor.output = colliders()
+            .filter_map(|(entity, collider_transform, collider)| {
+                let CastResult { proximity, normal } =
+                    collider.cast_ray(collider_transform, probe_origin, -up, sensor.probe_depth)?;
+                Some((entity, proximity, normal))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .filter(|(_, proximity, _)| 0.0 < *proximity)
+            .map(|(entity, proximity, normal)| {
+                let headroom = if proximity < sensor.headroom_range {
+                    colliders()
+                        .filter_map(|(_, collider_transform, collider)| {
+                            collider.cast_ray(
+                                collider_transform,
+                                probe_origin,
+                                up,
+                                sensor.headroom_range - proximity,
+                            )
+                        })
+                        .map(|CastResult { proximity, .. }| proximity)
+                        .min_by(|a, b| a.total_cmp(b))
+                        .map_or(Float::INFINITY, |ceiling| proximity + ceiling)
+                } else {
+                    Float::INFINITY
+                };
+                TnuaLedgeSensorOutput {
+                    entity,
+                    point: probe_origin - proximity * up.adjust_precision(),
+                    normal,
+                    headroom,
+                }
+            });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_step_sensors_system(
+    mut query: Query<(Entity, &Transform, &mut TnuaStepSensor, Option<&TnuaToggle>)>,
+    colliders_query: Query<
+        (Entity, &Transform, &TnuaMockCollider),
+        (Without<TnuaGhostPlatform>, Without<TnuaWaterVolume>),
+    >,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.translation.adjust_precision()
+            + transform.rotation.adjust_precision() * sensor.cast_origin;
+
+        let up = sensor.up;
+        let probe_origin = cast_origin + sensor.reach * sensor.cast_direction.adjust_precision();
+        sensor.output = colliders_query
+            .iter()
+            .filter(|(entity, ..)| *entity != owner_entity)
+            .filter_map(|(entity, collider_transform, collider)| {
+                let CastResult { proximity, normal } =
+                    collider.cast_ray(collider_transform, probe_origin, -up, sensor.probe_depth)?;
+                Some((entity, proximity, normal))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .filter(|(_, proximity, _)| 0.0 < *proximity)
+            .map(|(entity, proximity, normal)| TnuaStepSensorOutput {
+                entity,
+                point: probe_origin - proximity * up.adjust_precision(),
+                normal,
+            });
+    }
+}
+
+fn apply_motors_system(
+    mut query: Query<(
+        &TnuaMotor,
+        &mut TnuaMockVelocity,
+        &mut TnuaMockExternalAcceleration,
+        Option<&TnuaToggle>,
+    )>,
+) {
+    for (motor, mut velocity, mut external_acceleration, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled | TnuaToggle::SenseOnly => {
+                *external_acceleration = Default::default();
+                continue;
+            }
+            TnuaToggle::Enabled => {}
+        }
+        if motor.lin.boost.is_finite() {
+            velocity.linvel += motor.lin.boost;
+        }
+        if motor.lin.acceleration.is_finite() {
+            external_acceleration.linear = motor.lin.acceleration;
+        }
+        if motor.ang.boost.is_finite() {
+            velocity.angvel += motor.ang.boost;
+        }
+        if motor.ang.acceleration.is_finite() {
+            external_acceleration.angular = motor.ang.acceleration;
+        }
+    }
+}
+
+fn step_simulation_system(
+    time: Res<Time>,
+    config: Res<TnuaMockPhysicsConfig>,
+    mut query: Query<(
+        &TnuaMockRigidBody,
+        &mut Transform,
+        &mut TnuaMockVelocity,
+        Option<&TnuaMockExternalAcceleration>,
+    )>,
+) {
+    let frame_duration = time.delta_seconds().adjust_precision();
+    if frame_duration == 0.0 {
+        return;
+    }
+    for (rigid_body, mut transform, mut velocity, external_acceleration) in query.iter_mut() {
+        if *rigid_body == TnuaMockRigidBody::Dynamic {
+            velocity.linvel += config.gravity * frame_duration;
+            if let Some(external_acceleration) = external_acceleration {
+                velocity.linvel += external_acceleration.linear * frame_duration;
+                velocity.angvel += external_acceleration.angular * frame_duration;
+            }
+        }
+        transform.translation += (velocity.linvel * frame_duration).f32();
+        transform.rotation = (Quat::from_scaled_axis((velocity.angvel * frame_duration).f32())
+            * transform.rotation)
+            .normalize();
+    }
+}
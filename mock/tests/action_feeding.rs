@@ -0,0 +1,48 @@
+mod common;
+
+use bevy_tnua::builtins::{TnuaBuiltinJump, TnuaBuiltinWallJump};
+use bevy_tnua::math::Vector3;
+use bevy_tnua::TnuaAction;
+
+use common::{walk, MockWorld};
+
+#[test]
+fn delayed_action_is_initiated_with_the_latest_input() {
+    let mut world = MockWorld::empty();
+    let character = world.spawn_character(Vector3::new(0.0, 20.0, 0.0));
+    world.update();
+
+    // No wall yet - the wall jump is delayed by its input buffer.
+    world.run(character, 3, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinWallJump {
+            wall_normal: Vector3::ZERO,
+            jump: TnuaBuiltinJump {
+                height: 2.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    });
+    assert_eq!(world.controller(character).action_name(), None);
+
+    // The wall is detected while the action is still being fed - it should start with the
+    // updated normal.
+    world.run(character, 1, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinWallJump {
+            wall_normal: Vector3::X,
+            jump: TnuaBuiltinJump {
+                height: 2.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+    });
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinWallJump::NAME)
+    );
+    assert!(0.0 < world.velocity(character).x);
+    assert!(0.0 < world.velocity(character).y);
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinChargeJump;
+use bevy_tnua::controller::TnuaActionEnded;
+use bevy_tnua::math::Float;
+use bevy_tnua::TnuaAction;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+fn charged_jump_apex(charge_frames: usize) -> Float {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    world.run(character, charge_frames, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinChargeJump::default());
+    });
+    let mut apex = world.translation(character).y;
+    for _ in 0..180 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk());
+        });
+        apex = apex.max(world.translation(character).y);
+    }
+    apex - FLOAT_HEIGHT
+}
+
+#[test]
+fn charge_jump_height_depends_on_the_charge_duration() {
+    let TnuaBuiltinChargeJump {
+        min_height,
+        max_height,
+        ..
+    } = TnuaBuiltinChargeJump::default();
+    // The charge only starts progressing from the second frame the action is fed.
+    assert_close(charged_jump_apex(1), min_height, 0.1 * min_height);
+    let half_height = 0.5 * (min_height + max_height);
+    assert_close(charged_jump_apex(31), half_height, 0.1 * half_height);
+    assert_close(charged_jump_apex(120), max_height, 0.1 * max_height);
+}
+
+#[test]
+fn charge_jump_does_not_jump_while_charging() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    world.run(character, 30, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinChargeJump::default());
+    });
+    assert_close(world.translation(character).y, FLOAT_HEIGHT, 0.01);
+    let (_, state) = world
+        .controller(character)
+        .concrete_action::<TnuaBuiltinChargeJump>()
+        .expect("not charging");
+    let progress = state.charge_progress().expect("not charging");
+    assert_close(progress, 29.0 / 60.0, 0.001);
+}
+
+#[test]
+fn charge_jump_ends_when_the_character_lands() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    let mut ended_reader = world
+        .app
+        .world
+        .resource::<Events<TnuaActionEnded>>()
+        .get_reader();
+    let mut took_off_at = None;
+    let mut ended_at = None;
+    for frame in 0..180 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk());
+            if frame < 30 {
+                controller.action(TnuaBuiltinChargeJump::default());
+            }
+        });
+        if took_off_at.is_none() && FLOAT_HEIGHT + 0.1 < world.translation(character).y {
+            took_off_at = Some(frame);
+        }
+        let events = world.app.world.resource::<Events<TnuaActionEnded>>();
+        for event in ended_reader.read(events) {
+            assert_eq!(event.action_name, TnuaBuiltinChargeJump::NAME);
+            assert_eq!(ended_at, None, "the action ended twice");
+            ended_at = Some(frame);
+        }
+    }
+    let took_off_at = took_off_at.expect("the character did not jump");
+    let ended_at = ended_at.expect("the action did not end");
+    // Releasing the button starts the jump - the action only ends when the character lands.
+    assert!(took_off_at + 10 < ended_at);
+    assert_eq!(world.controller(character).action_name(), None);
+    assert_close(world.translation(character).y, FLOAT_HEIGHT, 0.05);
+}
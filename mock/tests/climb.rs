@@ -0,0 +1,113 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::{
+    TnuaBuiltinClimb, TnuaBuiltinClimbExit, TnuaBuiltinClimbState, TnuaClimbable,
+};
+use bevy_tnua::math::{AdjustPrecision, Float, Vector2, Vector3};
+use bevy_tnua::subservient_sensors::TnuaSubservientSensor;
+use bevy_tnua::TnuaProximitySensor;
+
+use common::{assert_close, MockWorld};
+
+const WALL_TOP: Float = 5.0;
+
+/// A character standing in front of a climbable wall whose face is at `z = -2`, with a forward
+/// sensor for detecting it.
+fn world_with_climbable_wall() -> (MockWorld, Entity, Entity) {
+    let mut world = MockWorld::with_ground();
+    let wall = world.spawn_block((-5.0, 5.0), (-3.0, -2.0), WALL_TOP);
+    world.app.world.entity_mut(wall).insert(TnuaClimbable);
+    let character = world.spawn_standing_character(0.0, 0.0);
+    let sensor = world
+        .app
+        .world
+        .spawn((
+            Transform::default(),
+            TnuaSubservientSensor {
+                owner_entity: character,
+            },
+            TnuaProximitySensor {
+                cast_direction: Direction3d::NEG_Z,
+                cast_range: 2.0,
+                ..Default::default()
+            },
+        ))
+        .id();
+    world.update();
+    (world, character, sensor)
+}
+
+fn climb(world: &mut MockWorld, character: Entity, sensor: Entity, desired_velocity: Vector2) {
+    let output = world.get::<TnuaProximitySensor>(sensor).output.clone();
+    world.controller_mut(character).basis(TnuaBuiltinClimb {
+        desired_velocity,
+        surface_normal: output
+            .as_ref()
+            .map_or(Vector3::ZERO, |output| output.normal.adjust_precision()),
+        surface_distance: output.as_ref().map_or(0.0, |output| output.proximity),
+        exit_ground_distance: 1.5,
+        ..Default::default()
+    });
+    world.update();
+}
+
+fn climb_state(world: &MockWorld, character: Entity) -> TnuaBuiltinClimbState {
+    world
+        .controller(character)
+        .concrete_basis::<TnuaBuiltinClimb>()
+        .unwrap()
+        .1
+        .clone()
+}
+
+#[test]
+fn climbing_snaps_to_the_surface_and_moves_along_it() {
+    let (mut world, character, sensor) = world_with_climbable_wall();
+    for _ in 0..60 {
+        climb(&mut world, character, sensor, Vector2::new(0.0, 2.0));
+    }
+    let snap_distance = TnuaBuiltinClimb::default().snap_distance;
+    assert_close(world.translation(character).z, -2.0 + snap_distance, 0.02);
+    assert_close(world.velocity(character).y, 2.0, 0.01);
+    assert_close(world.velocity(character).x, 0.0, 0.01);
+    assert_eq!(climb_state(&world, character).exit(), None);
+}
+
+#[test]
+fn climbing_holds_in_place_against_gravity() {
+    let (mut world, character, sensor) = world_with_climbable_wall();
+    for _ in 0..60 {
+        climb(&mut world, character, sensor, Vector2::new(0.0, 2.0));
+    }
+    let height = world.translation(character).y;
+    for _ in 0..60 {
+        climb(&mut world, character, sensor, Vector2::ZERO);
+    }
+    assert_close(world.translation(character).y, height, 0.1);
+    assert_close(world.velocity(character).y, 0.0, 0.01);
+}
+
+#[test]
+fn climbing_past_the_top_dismounts_over_it() {
+    let (mut world, character, sensor) = world_with_climbable_wall();
+    let mut dismounted = false;
+    for _ in 0..300 {
+        climb(&mut world, character, sensor, Vector2::new(0.0, 3.0));
+        if let Some(exit) = climb_state(&world, character).exit() {
+            assert_eq!(exit, TnuaBuiltinClimbExit::TopDismount);
+            dismounted = true;
+            break;
+        }
+    }
+    assert!(dismounted);
+    let position = world.translation(character);
+    assert!(
+        WALL_TOP < position.y,
+        "dismounted below the top at {position}"
+    );
+    assert!(
+        position.z < -2.0,
+        "did not get over the wall - at {position}"
+    );
+}
@@ -0,0 +1,155 @@
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_tnua::math::{AdjustPrecision, AsF32, Float, Vector3};
+use bevy_tnua::prelude::*;
+use bevy_tnua::TnuaProximitySensor;
+use bevy_tnua_mock::*;
+
+pub const FRAME_DURATION: Float = 1.0 / 60.0;
+
+pub const FLOAT_HEIGHT: Float = 1.5;
+
+/// A headless app running Tnua on top of the mock backend, at a fixed frame rate.
+pub struct MockWorld {
+    pub app: App,
+}
+
+impl MockWorld {
+    /// A world with no ground at all.
+    pub fn empty() -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            FRAME_DURATION as f64,
+        )));
+        app.add_plugins((TnuaControllerPlugin::default(), TnuaMockPlugin::default()));
+        // The first update does not advance the time.
+        app.update();
+        Self { app }
+    }
+
+    /// A world with an infinite ground plane at `y = 0`.
+    pub fn with_ground() -> Self {
+        let mut world = Self::empty();
+        world.spawn_collider(Transform::default(), TnuaMockCollider::Plane);
+        world
+    }
+
+    pub fn spawn_collider(&mut self, transform: Transform, collider: TnuaMockCollider) -> Entity {
+        self.app.world.spawn((transform, collider)).id()
+    }
+
+    /// A box whose top face is at the given height, spanning the given ranges on the X and Z
+    /// axes, with its bottom at `y = -10`.
+    pub fn spawn_block(&mut self, x: (Float, Float), z: (Float, Float), top: Float) -> Entity {
+        let min = Vector3::new(x.0, -10.0, z.0);
+        let max = Vector3::new(x.1, top, z.1);
+        self.spawn_collider(
+            Transform::from_translation((0.5 * (min + max)).f32()),
+            TnuaMockCollider::Cuboid {
+                half_extents: 0.5 * (max - min),
+            },
+        )
+    }
+
+    /// A character with a [`TnuaController`], whose center starts at the given position.
+    pub fn spawn_character(&mut self, position: Vector3) -> Entity {
+        self.app
+            .world
+            .spawn((
+                Transform::from_translation(position.f32()),
+                TnuaMockRigidBody::Dynamic,
+                TnuaMockIOBundle::default(),
+                TnuaControllerBundle::default(),
+            ))
+            .id()
+    }
+
+    /// A character standing still on ground at `y = 0`.
+    pub fn spawn_standing_character(&mut self, x: Float, z: Float) -> Entity {
+        let character = self.spawn_character(Vector3::new(x, FLOAT_HEIGHT, z));
+        self.settle(character);
+        character
+    }
+
+    /// Run a single frame.
+    pub fn update(&mut self) {
+        self.app.update();
+    }
+
+    /// Run the given number of frames, calling `control` before each of them.
+    pub fn run(
+        &mut self,
+        character: Entity,
+        frames: usize,
+        mut control: impl FnMut(&mut TnuaController),
+    ) {
+        for _ in 0..frames {
+            control(&mut self.controller_mut(character));
+            self.update();
+        }
+    }
+
+    pub fn get<C: Component>(&self, entity: Entity) -> &C {
+        self.app.world.get::<C>(entity).unwrap()
+    }
+
+    pub fn get_mut<C: Component>(&mut self, entity: Entity) -> Mut<'_, C> {
+        self.app.world.get_mut::<C>(entity).unwrap()
+    }
+
+    pub fn controller(&self, character: Entity) -> &TnuaController {
+        self.get(character)
+    }
+
+    pub fn controller_mut(&mut self, character: Entity) -> Mut<'_, TnuaController> {
+        self.get_mut(character)
+    }
+
+    pub fn translation(&self, entity: Entity) -> Vector3 {
+        self.get::<Transform>(entity).translation.adjust_precision()
+    }
+
+    pub fn velocity(&self, entity: Entity) -> Vector3 {
+        self.get::<TnuaMockVelocity>(entity).linvel
+    }
+
+    pub fn set_velocity(&mut self, entity: Entity, velocity: Vector3) {
+        self.get_mut::<TnuaMockVelocity>(entity).linvel = velocity;
+    }
+
+    pub fn ground_proximity(&self, character: Entity) -> Option<Float> {
+        Some(
+            self.get::<TnuaProximitySensor>(character)
+                .output
+                .as_ref()?
+                .proximity,
+        )
+    }
+
+    /// Let the character settle at its float height, standing still.
+    pub fn settle(&mut self, character: Entity) {
+        self.run(character, 60, |controller| {
+            controller.basis(walk());
+        });
+    }
+}
+
+/// The walk basis used by the tests, floating at [`FLOAT_HEIGHT`].
+pub fn walk() -> TnuaBuiltinWalk {
+    TnuaBuiltinWalk {
+        float_height: FLOAT_HEIGHT,
+        ..Default::default()
+    }
+}
+
+pub fn assert_close(actual: Float, expected: Float, tolerance: Float) {
+    assert!(
+        (actual - expected).abs() <= tolerance,
+        "expected {expected} (+-{tolerance}), got {actual}"
+    );
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use bevy_tnua::builtins::TnuaBuiltinDash;
+use bevy_tnua::math::Vector3;
+
+use common::{assert_close, walk, MockWorld};
+
+#[test]
+fn dash_travels_its_displacement() {
+    for distance in [3.0, 6.0] {
+        let mut world = MockWorld::with_ground();
+        let character = world.spawn_standing_character(0.0, 0.0);
+        let start = world.translation(character);
+        world.run(character, 120, |controller| {
+            controller.basis(walk());
+            controller.action(TnuaBuiltinDash {
+                displacement: Vector3::X * distance,
+                brake_to_speed: 0.0,
+                ..Default::default()
+            });
+        });
+        let displacement = world.translation(character) - start;
+        assert_close(displacement.x, distance, 0.1 * distance);
+        assert_close(displacement.z, 0.0, 0.01);
+        assert_close(world.velocity(character).x, 0.0, 0.01);
+    }
+}
+
+#[test]
+fn dash_is_not_possible_in_the_air() {
+    let mut world = MockWorld::empty();
+    let character = world.spawn_character(Vector3::new(0.0, 100.0, 0.0));
+    // Wait for the coyote time to pass.
+    world.run(character, 30, |controller| {
+        controller.basis(walk());
+    });
+    world.run(character, 60, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinDash {
+            displacement: Vector3::X * 5.0,
+            ..Default::default()
+        });
+    });
+    assert_close(world.translation(character).x, 0.0, 0.01);
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use bevy_tnua::builtins::TnuaBuiltinDirectionalJump;
+use bevy_tnua::math::{Float, Vector3};
+use bevy_tnua::prelude::*;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+/// Returns the horizontal position where the character got back to the height it jumped from, and
+/// the apex of the jump.
+fn directional_jump(direction: Vector3, distance: Float) -> (Vector3, Float) {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    let mut apex = world.translation(character).y;
+    for _ in 0..180 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk());
+            controller.action(TnuaBuiltinDirectionalJump {
+                direction,
+                distance,
+                jump: TnuaBuiltinJump {
+                    height: 2.0,
+                    ..Default::default()
+                },
+                ..Default::default()
+            });
+        });
+        let translation = world.translation(character);
+        apex = apex.max(translation.y);
+        if FLOAT_HEIGHT + 1.0 < apex && translation.y <= FLOAT_HEIGHT {
+            return (translation.reject_from(Vector3::Y), apex - FLOAT_HEIGHT);
+        }
+    }
+    panic!("character did not land (apex was {apex})");
+}
+
+#[test]
+fn directional_jump_travels_its_distance() {
+    for distance in [3.0, 6.0] {
+        let (landing, height) = directional_jump(Vector3::X, distance);
+        assert_close(height, 2.0, 0.2);
+        // At 60 frames per second the character may pass the landing height within the frame.
+        assert_close(landing.x, distance, 0.1 * distance);
+        assert_close(landing.z, 0.0, 0.001);
+    }
+}
+
+#[test]
+fn directional_jump_ignores_the_vertical_part_of_the_direction() {
+    let (landing, height) = directional_jump(Vector3::new(0.0, 1.0, -1.0), 4.0);
+    assert_close(height, 2.0, 0.2);
+    assert_close(landing.x, 0.0, 0.001);
+    assert_close(landing.z, -4.0, 0.4);
+}
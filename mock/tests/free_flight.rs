@@ -0,0 +1,53 @@
+mod common;
+
+use bevy_tnua::builtins::TnuaBuiltinFreeFlight;
+use bevy_tnua::math::Vector3;
+
+use common::{assert_close, MockWorld};
+
+#[test]
+fn free_flight_hovers_in_place() {
+    let mut world = MockWorld::empty();
+    let character = world.spawn_character(Vector3::new(0.0, 10.0, 0.0));
+    world.run(character, 120, |controller| {
+        controller.basis(TnuaBuiltinFreeFlight::default());
+    });
+    assert_close(world.translation(character).y, 10.0, 0.01);
+    assert_close(world.velocity(character).length(), 0.0, 0.01);
+}
+
+#[test]
+fn free_flight_reaches_desired_velocity_in_all_directions() {
+    let mut world = MockWorld::empty();
+    let character = world.spawn_character(Vector3::new(0.0, 10.0, 0.0));
+    let desired_velocity = Vector3::new(3.0, 4.0, -2.0);
+    world.run(character, 60, |controller| {
+        controller.basis(TnuaBuiltinFreeFlight {
+            desired_velocity,
+            ..Default::default()
+        });
+    });
+    let velocity = world.velocity(character);
+    assert_close(velocity.x, desired_velocity.x, 0.01);
+    assert_close(velocity.y, desired_velocity.y, 0.01);
+    assert_close(velocity.z, desired_velocity.z, 0.01);
+
+    world.run(character, 60, |controller| {
+        controller.basis(TnuaBuiltinFreeFlight::default());
+    });
+    assert_close(world.velocity(character).length(), 0.0, 0.01);
+}
+
+#[test]
+fn free_flight_without_gravity_compensation_sinks_when_acceleration_is_too_weak() {
+    let mut world = MockWorld::empty();
+    let character = world.spawn_character(Vector3::new(0.0, 10.0, 0.0));
+    world.run(character, 60, |controller| {
+        controller.basis(TnuaBuiltinFreeFlight {
+            compensate_gravity: false,
+            acceleration: 5.0,
+            ..Default::default()
+        });
+    });
+    assert!(world.translation(character).y < 9.0);
+}
@@ -0,0 +1,72 @@
+mod common;
+
+use bevy_tnua::builtins::TnuaBuiltinGlide;
+use bevy_tnua::math::Vector3;
+use bevy_tnua::TnuaAction;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+#[test]
+fn glide_caps_the_fall_speed() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_character(Vector3::new(0.0, 100.0, 0.0));
+    world.run(character, 120, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinGlide::default());
+    });
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinGlide::NAME)
+    );
+    let terminal_fall_speed = TnuaBuiltinGlide::default().terminal_fall_speed;
+    assert_close(world.velocity(character).y, -terminal_fall_speed, 0.01);
+}
+
+#[test]
+fn glide_converts_fall_speed_into_forward_speed() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_character(Vector3::new(0.0, 100.0, 0.0));
+    // Fall fast before opening the glider.
+    world.run(character, 30, |controller| {
+        controller.basis(walk());
+    });
+    world.set_velocity(character, Vector3::new(1.0, -10.0, 0.0));
+    world.run(character, 60, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinGlide::default());
+    });
+    let velocity = world.velocity(character);
+    let TnuaBuiltinGlide {
+        terminal_fall_speed,
+        fall_to_forward_ratio,
+        ..
+    } = TnuaBuiltinGlide::default();
+    assert_close(velocity.y, -terminal_fall_speed, 0.01);
+    // Most of the fall speed that was cancelled was converted into forward speed.
+    assert!(
+        1.0 + 0.5 * fall_to_forward_ratio * (10.0 - terminal_fall_speed) < velocity.x,
+        "forward speed is only {}",
+        velocity.x,
+    );
+    assert_close(velocity.z, 0.0, 0.01);
+}
+
+#[test]
+fn glide_ends_on_landing() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_character(Vector3::new(0.0, 5.0, 0.0));
+    world.run(character, 30, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinGlide::default());
+    });
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinGlide::NAME)
+    );
+    world.run(character, 180, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinGlide::default());
+    });
+    assert_eq!(world.controller(character).action_name(), None);
+    assert_close(world.translation(character).y, FLOAT_HEIGHT, 0.01);
+}
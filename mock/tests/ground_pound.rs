@@ -0,0 +1,86 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::{TnuaBuiltinGroundPound, TnuaBuiltinGroundPoundState};
+use bevy_tnua::math::Vector3;
+
+use common::{assert_close, walk, MockWorld};
+
+/// A character high in the air, after its coyote time has passed, moving sideways.
+fn falling_character() -> (MockWorld, Entity) {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_character(Vector3::new(0.0, 50.0, 0.0));
+    world.run(character, 30, |controller| {
+        controller.basis(walk());
+    });
+    world.set_velocity(character, Vector3::new(5.0, 3.0, 0.0));
+    (world, character)
+}
+
+fn ground_pound_state(world: &MockWorld, character: Entity) -> TnuaBuiltinGroundPoundState {
+    world
+        .controller(character)
+        .concrete_action::<TnuaBuiltinGroundPound>()
+        .unwrap()
+        .1
+        .clone()
+}
+
+#[test]
+fn ground_pound_windup_freezes_only_horizontal_movement() {
+    let (mut world, character) = falling_character();
+    world.run(character, 5, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinGroundPound::default());
+    });
+    assert!(matches!(
+        ground_pound_state(&world, character),
+        TnuaBuiltinGroundPoundState::Windup { .. }
+    ));
+    let velocity = world.velocity(character);
+    assert_close(velocity.x, 0.0, 0.001);
+    // Gravity still applies.
+    assert!(velocity.y < 0.0);
+}
+
+#[test]
+fn ground_pound_windup_can_hang_in_the_air() {
+    let (mut world, character) = falling_character();
+    world.run(character, 5, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinGroundPound {
+            windup_hang: true,
+            ..Default::default()
+        });
+    });
+    assert_close(world.velocity(character).length(), 0.0, 0.001);
+}
+
+#[test]
+fn ground_pound_slams_and_reports_the_impact() {
+    let (mut world, character) = falling_character();
+    let ground_pound = TnuaBuiltinGroundPound::default();
+    let windup_frames = (ground_pound.windup_duration * 60.0) as usize;
+    world.run(character, windup_frames + 5, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinGroundPound::default());
+    });
+    assert!(matches!(
+        ground_pound_state(&world, character),
+        TnuaBuiltinGroundPoundState::Slamming
+    ));
+    assert_close(world.velocity(character).y, -ground_pound.slam_speed, 0.001);
+
+    let mut impact = None;
+    for _ in 0..180 {
+        world.controller_mut(character).basis(walk());
+        world.update();
+        let state = ground_pound_state(&world, character);
+        if state.just_landed() {
+            impact = state.impact();
+            break;
+        }
+    }
+    let (_, impact_speed) = impact.expect("the character did not land");
+    assert_close(impact_speed, ground_pound.slam_speed, 0.001);
+}
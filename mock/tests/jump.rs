@@ -0,0 +1,151 @@
+mod common;
+
+use bevy_tnua::builtins::{TnuaBuiltinWalkState, TnuaGroundContactChange};
+use bevy_tnua::math::{Float, Vector3};
+use bevy_tnua::prelude::*;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+fn jump_apex(height: Float) -> Float {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    let mut apex = world.translation(character).y;
+    for _ in 0..180 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk());
+            controller.action(TnuaBuiltinJump {
+                height,
+                ..Default::default()
+            });
+        });
+        apex = apex.max(world.translation(character).y);
+    }
+    apex - FLOAT_HEIGHT
+}
+
+#[test]
+fn jump_reaches_its_height() {
+    for height in [1.0, 2.0, 4.0] {
+        // At 60 frames per second the discrete integration overshoots the apex a little.
+        assert_close(jump_apex(height), height, 0.1 * height);
+    }
+}
+
+#[test]
+fn jump_is_higher_when_higher_height_is_set() {
+    assert!(jump_apex(2.0) < jump_apex(3.0));
+}
+
+#[test]
+fn released_jump_is_shorter() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    let mut apex = world.translation(character).y;
+    for frame in 0..180 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk());
+            if frame < 5 {
+                controller.action(TnuaBuiltinJump {
+                    height: 4.0,
+                    ..Default::default()
+                });
+            }
+        });
+        apex = apex.max(world.translation(character).y);
+    }
+    // Releasing the button applies the shorten extra gravity, so the character should fall well
+    // short of the full 4 meters.
+    assert!(apex - FLOAT_HEIGHT < 3.0, "{}", apex - FLOAT_HEIGHT);
+}
+
+/// Walk off the edge of a platform that ends at `x = 0`, and try to jump the given number of
+/// frames after the character loses its footing. Returns whether the jump started.
+fn jump_after_walking_off_a_ledge(frames_after_takeoff: usize) -> bool {
+    let mut world = MockWorld::empty();
+    world.spawn_block((-20.0, 0.0), (-5.0, 5.0), 0.0);
+    let character = world.spawn_standing_character(-2.0, 0.0);
+    let walk_forward = || TnuaBuiltinWalk {
+        desired_velocity: Vector3::X * 4.0,
+        ..walk()
+    };
+    let mut took_off = false;
+    for _ in 0..120 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk_forward());
+        });
+        let (_, state) = world
+            .controller(character)
+            .concrete_basis::<TnuaBuiltinWalk>()
+            .unwrap();
+        if matches!(
+            state.ground_contact_change(),
+            Some(TnuaGroundContactChange::TookOff { .. })
+        ) {
+            took_off = true;
+            break;
+        }
+    }
+    assert!(took_off);
+    world.run(character, frames_after_takeoff, |controller| {
+        controller.basis(walk_forward());
+    });
+    world.run(character, 30, |controller| {
+        controller.basis(walk_forward());
+        controller.action(TnuaBuiltinJump {
+            height: 2.0,
+            ..Default::default()
+        });
+    });
+    world
+        .controller(character)
+        .concrete_action::<TnuaBuiltinJump>()
+        .is_some()
+}
+
+#[test]
+fn jump_is_possible_during_coyote_time() {
+    // The default coyote time is 0.15 seconds - 9 frames.
+    for frames in [0, 4, 7] {
+        assert!(
+            jump_after_walking_off_a_ledge(frames),
+            "could not jump {frames} frames after walking off the ledge"
+        );
+    }
+}
+
+#[test]
+fn jump_is_not_possible_after_coyote_time() {
+    for frames in [10, 30] {
+        assert!(
+            !jump_after_walking_off_a_ledge(frames),
+            "could jump {frames} frames after walking off the ledge"
+        );
+    }
+}
+
+#[test]
+fn walk_state_reports_landing_after_jump() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    let mut landings = Vec::new();
+    for _ in 0..180 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk());
+            controller.action(TnuaBuiltinJump {
+                height: 2.0,
+                ..Default::default()
+            });
+        });
+        let (_, state): (_, &TnuaBuiltinWalkState) = world
+            .controller(character)
+            .concrete_basis::<TnuaBuiltinWalk>()
+            .unwrap();
+        if let Some(TnuaGroundContactChange::Landed { airtime, .. }) = state.ground_contact_change()
+        {
+            landings.push(*airtime);
+        }
+    }
+    assert_eq!(landings.len(), 1);
+    // The airtime only starts once the character is beyond the cling distance.
+    assert!(0.5 < landings[0] && landings[0] < 1.0, "{landings:?}");
+}
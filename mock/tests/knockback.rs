@@ -0,0 +1,108 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::{TnuaBuiltinJump, TnuaBuiltinKnockback};
+use bevy_tnua::math::{Float, Vector3};
+use bevy_tnua::TnuaAction;
+
+use common::{assert_close, walk, MockWorld};
+
+const STUN_DURATION: Float = 0.5;
+const STUN_FRAMES: usize = 30;
+
+fn knockback(uncancellable: bool) -> TnuaBuiltinKnockback {
+    TnuaBuiltinKnockback {
+        impulse: Vector3::X * 6.0,
+        stun_duration: STUN_DURATION,
+        uncancellable,
+        ..Default::default()
+    }
+}
+
+fn hit(world: &mut MockWorld, character: Entity, knockback: TnuaBuiltinKnockback) {
+    let mut controller = world.controller_mut(character);
+    controller.basis(walk());
+    controller.action(knockback);
+    world.update();
+}
+
+#[test]
+fn knockback_applies_the_impulse_and_stuns_for_the_stun_duration() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    hit(&mut world, character, knockback(false));
+    assert_close(world.velocity(character).x, 6.0, 0.001);
+
+    // The walk basis wants to stand still, but it has no control during the stun.
+    world.run(character, STUN_FRAMES - 2, |controller| {
+        controller.basis(walk());
+    });
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinKnockback::NAME)
+    );
+    assert_close(world.velocity(character).x, 6.0, 0.001);
+
+    world.run(character, 2, |controller| {
+        controller.basis(walk());
+    });
+    assert_eq!(world.controller(character).action_name(), None);
+    world.run(character, 10, |controller| {
+        controller.basis(walk());
+    });
+    assert_close(world.velocity(character).x, 0.0, 0.001);
+}
+
+#[test]
+fn knockback_without_stun_still_applies_the_impulse() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    hit(
+        &mut world,
+        character,
+        TnuaBuiltinKnockback {
+            stun_duration: 0.0,
+            ..knockback(false)
+        },
+    );
+    assert_close(world.velocity(character).x, 6.0, 0.001);
+    world.run(character, 1, |controller| {
+        controller.basis(walk());
+    });
+    assert_eq!(world.controller(character).action_name(), None);
+}
+
+fn jump_during_stun(uncancellable: bool) -> Vec<Option<&'static str>> {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    hit(&mut world, character, knockback(uncancellable));
+    let mut action_names = Vec::new();
+    for _ in 0..STUN_FRAMES {
+        let mut controller = world.controller_mut(character);
+        controller.basis(walk());
+        controller.action(TnuaBuiltinJump {
+            height: 2.0,
+            ..Default::default()
+        });
+        world.update();
+        action_names.push(world.controller(character).action_name());
+    }
+    action_names
+}
+
+#[test]
+fn knockback_can_be_cancelled_by_a_jump() {
+    let action_names = jump_during_stun(false);
+    assert_eq!(action_names[0], Some(TnuaBuiltinJump::NAME));
+}
+
+#[test]
+fn uncancellable_knockback_delays_the_jump_until_the_stun_is_over() {
+    let action_names = jump_during_stun(true);
+    let stun_end = action_names
+        .iter()
+        .position(|name| *name != Some(TnuaBuiltinKnockback::NAME))
+        .expect("the stun did not end");
+    assert!(STUN_FRAMES - 3 <= stun_end);
+    assert_eq!(action_names[stun_end], Some(TnuaBuiltinJump::NAME));
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinLedgeGrab;
+use bevy_tnua::math::{AdjustPrecision, Float, Vector3};
+use bevy_tnua::{TnuaAction, TnuaLedgeSensor};
+
+use common::{assert_close, walk, MockWorld};
+
+const LEDGE_TOP: Float = 3.0;
+
+/// A character falling in front of a ledge whose edge is at `z = -2`, spanning `-2 < x < 2`.
+fn world_with_ledge() -> (MockWorld, Entity) {
+    let mut world = MockWorld::with_ground();
+    world.spawn_block((-2.0, 2.0), (-4.0, -2.0), LEDGE_TOP);
+    let character = world.spawn_character(Vector3::new(0.0, LEDGE_TOP, -1.5));
+    world
+        .app
+        .world
+        .entity_mut(character)
+        .insert(TnuaLedgeSensor {
+            // A bit higher than the hang offset, so that the sensor can see the ledge while
+            // hanging from it.
+            cast_origin: Vector3::new(0.0, 1.2, 0.0),
+            probe_depth: 1.5,
+            ..Default::default()
+        });
+    world.update();
+    (world, character)
+}
+
+fn grab_ledge(world: &mut MockWorld, character: Entity, desired_shimmy: Float, climb_up: bool) {
+    let sensor = world.get::<TnuaLedgeSensor>(character);
+    let ledge_direction = sensor.cast_direction.adjust_precision();
+    let (ledge_point, ledge_normal) = sensor
+        .output
+        .as_ref()
+        .map_or((Vector3::ZERO, Vector3::ZERO), |output| {
+            (output.point, output.normal.adjust_precision())
+        });
+    let mut controller = world.controller_mut(character);
+    controller.basis(walk());
+    controller.action(TnuaBuiltinLedgeGrab {
+        ledge_point,
+        ledge_normal,
+        ledge_direction,
+        desired_shimmy,
+        climb_up,
+        ..Default::default()
+    });
+    world.update();
+}
+
+#[test]
+fn ledge_grab_hangs_below_the_ledge() {
+    let (mut world, character) = world_with_ledge();
+    for _ in 0..60 {
+        grab_ledge(&mut world, character, 0.0, false);
+    }
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinLedgeGrab::NAME)
+    );
+    let hang_offset = TnuaBuiltinLedgeGrab::default().hang_offset;
+    assert_close(
+        world.translation(character).y,
+        LEDGE_TOP - hang_offset,
+        0.01,
+    );
+    assert_close(world.velocity(character).length(), 0.0, 0.01);
+}
+
+#[test]
+fn ledge_grab_drops_when_shimmying_past_the_end_of_the_ledge() {
+    let (mut world, character) = world_with_ledge();
+    for _ in 0..30 {
+        grab_ledge(&mut world, character, 0.0, false);
+    }
+    let mut dropped_at = None;
+    for _ in 0..120 {
+        grab_ledge(&mut world, character, 2.0, false);
+        if world.controller(character).action_name().is_none() {
+            dropped_at = Some(world.translation(character));
+            break;
+        }
+    }
+    let dropped_at = dropped_at.expect("the character kept hanging past the end of the ledge");
+    assert_close(dropped_at.x, 2.0, 0.1);
+}
+
+#[test]
+fn ledge_grab_climbs_up_onto_the_ledge() {
+    let (mut world, character) = world_with_ledge();
+    for _ in 0..30 {
+        grab_ledge(&mut world, character, 0.0, false);
+    }
+    for _ in 0..120 {
+        grab_ledge(&mut world, character, 0.0, true);
+        if world.controller(character).action_name().is_none() {
+            break;
+        }
+    }
+    assert_eq!(world.controller(character).action_name(), None);
+    let position = world.translation(character);
+    assert!(LEDGE_TOP < position.y, "did not climb up - at {position}");
+    assert!(
+        position.z < -2.0,
+        "did not get onto the ledge - at {position}"
+    );
+}
@@ -0,0 +1,127 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinMantle;
+use bevy_tnua::math::{AdjustPrecision, AsF32, Float, Vector3};
+use bevy_tnua::{TnuaAction, TnuaLedgeSensor, TnuaProximitySensor};
+use bevy_tnua_mock::TnuaMockCollider;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+const OBSTACLE_TOP: Float = 1.0;
+const REQUIRED_HEADROOM: Float = 2.0;
+
+/// A character standing in front of an obstacle whose edge is at `z = -1.5`.
+fn world_with_obstacle(ceiling: Option<Float>) -> (MockWorld, Entity) {
+    let mut world = MockWorld::with_ground();
+    world.spawn_block((-2.0, 2.0), (-4.0, -1.5), OBSTACLE_TOP);
+    if let Some(ceiling) = ceiling {
+        world.spawn_collider(
+            Transform::from_translation(Vector3::new(0.0, ceiling + 0.5, -2.75).f32()),
+            TnuaMockCollider::Cuboid {
+                half_extents: Vector3::new(2.0, 0.5, 1.25),
+            },
+        );
+    }
+    let character = world.spawn_standing_character(0.0, 0.0);
+    world
+        .app
+        .world
+        .entity_mut(character)
+        .insert(TnuaLedgeSensor {
+            reach: 2.0,
+            probe_depth: 1.5,
+            headroom_range: REQUIRED_HEADROOM,
+            ..Default::default()
+        });
+    world.update();
+    (world, character)
+}
+
+fn mantle(world: &mut MockWorld, character: Entity) {
+    let sensor = world.get::<TnuaLedgeSensor>(character);
+    let direction = sensor.cast_direction.adjust_precision();
+    let action = sensor
+        .output
+        .as_ref()
+        .map(|output| TnuaBuiltinMantle {
+            obstacle_point: output.point,
+            obstacle_normal: output.normal.adjust_precision(),
+            direction,
+            headroom: output.headroom,
+            required_headroom: REQUIRED_HEADROOM,
+            end_height: FLOAT_HEIGHT,
+            ..Default::default()
+        })
+        .unwrap_or_default();
+    let mut controller = world.controller_mut(character);
+    controller.basis(walk());
+    controller.action(action);
+    world.update();
+}
+
+#[test]
+fn ledge_sensor_detects_the_headroom_above_the_obstacle() {
+    let (world, character) = world_with_obstacle(None);
+    let output = world
+        .get::<TnuaLedgeSensor>(character)
+        .output
+        .clone()
+        .unwrap();
+    assert_close(output.point.y, OBSTACLE_TOP, 0.001);
+    assert_eq!(output.headroom, Float::INFINITY);
+
+    let (world, character) = world_with_obstacle(Some(OBSTACLE_TOP + 1.2));
+    let output = world
+        .get::<TnuaLedgeSensor>(character)
+        .output
+        .clone()
+        .unwrap();
+    assert_close(output.headroom, 1.2, 0.001);
+}
+
+#[test]
+fn mantle_gets_onto_the_obstacle() {
+    let (mut world, character) = world_with_obstacle(None);
+    mantle(&mut world, character);
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinMantle::NAME)
+    );
+    let duration = TnuaBuiltinMantle::default().duration;
+    for _ in 0..(duration * 60.0) as usize + 5 {
+        mantle(&mut world, character);
+    }
+    assert_eq!(world.controller(character).action_name(), None);
+    let position = world.translation(character);
+    assert_close(position.y, OBSTACLE_TOP + FLOAT_HEIGHT, 0.1);
+    assert!(
+        position.z < -1.5,
+        "did not get onto the obstacle - at {position}"
+    );
+}
+
+#[test]
+fn mantle_measures_the_obstacle_height_from_the_ground() {
+    let (mut world, character) = world_with_obstacle(None);
+    // Cast from a meter below the character's center, so that it'll float a meter higher.
+    world.get_mut::<TnuaProximitySensor>(character).cast_origin = -Vector3::Y;
+    world.get_mut::<TnuaLedgeSensor>(character).cast_origin = -Vector3::Y;
+    world.settle(character);
+    assert_close(world.translation(character).y, FLOAT_HEIGHT + 1.0, 0.01);
+    mantle(&mut world, character);
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinMantle::NAME)
+    );
+}
+
+#[test]
+fn mantle_does_not_start_without_headroom() {
+    let (mut world, character) = world_with_obstacle(Some(OBSTACLE_TOP + 1.2));
+    for _ in 0..30 {
+        mantle(&mut world, character);
+    }
+    assert_eq!(world.controller(character).action_name(), None);
+    assert_close(world.translation(character).z, 0.0, 0.01);
+}
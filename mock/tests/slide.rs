@@ -0,0 +1,147 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::{TnuaBuiltinSlide, TnuaBuiltinWalk};
+use bevy_tnua::math::{AsF32, Float, Vector3};
+use bevy_tnua::TnuaAction;
+use bevy_tnua_mock::TnuaMockCollider;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+const FLOAT_OFFSET: Float = -0.7;
+
+fn slide() -> TnuaBuiltinSlide {
+    TnuaBuiltinSlide {
+        float_offset: FLOAT_OFFSET,
+        ..Default::default()
+    }
+}
+
+/// A character walking at the given speed on the X axis.
+fn walking_character(speed: Float) -> (MockWorld, Entity) {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    world.run(character, 60, |controller| {
+        controller.basis(TnuaBuiltinWalk {
+            desired_velocity: Vector3::X * speed,
+            ..walk()
+        });
+    });
+    (world, character)
+}
+
+#[test]
+fn slide_keeps_momentum_and_lowers_the_character() {
+    let (mut world, character) = walking_character(8.0);
+    world.run(character, 30, |controller| {
+        controller.basis(walk());
+        controller.action(slide());
+    });
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinSlide::NAME)
+    );
+    let friction = TnuaBuiltinSlide::default().friction;
+    assert_close(world.velocity(character).x, 8.0 - 0.5 * friction, 0.1);
+    assert_close(
+        world.translation(character).y,
+        FLOAT_HEIGHT + FLOAT_OFFSET,
+        0.02,
+    );
+}
+
+#[test]
+fn slide_ends_when_too_slow() {
+    let (mut world, character) = walking_character(8.0);
+    let TnuaBuiltinSlide {
+        friction,
+        min_speed,
+        ..
+    } = TnuaBuiltinSlide::default();
+    let frames = ((8.0 - min_speed) / friction * 60.0) as usize;
+    world.run(character, frames - 10, |controller| {
+        controller.basis(walk());
+        controller.action(slide());
+    });
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinSlide::NAME)
+    );
+    world.run(character, 20, |controller| {
+        controller.basis(walk());
+        controller.action(slide());
+    });
+    assert_eq!(world.controller(character).action_name(), None);
+    world.run(character, 60, |controller| {
+        controller.basis(walk());
+        controller.action(slide());
+    });
+    assert_close(world.translation(character).y, FLOAT_HEIGHT, 0.02);
+}
+
+#[test]
+fn slide_does_not_start_when_too_slow() {
+    let (mut world, character) = walking_character(1.0);
+    world.run(character, 10, |controller| {
+        controller.basis(walk());
+        controller.action(slide());
+    });
+    assert_eq!(world.controller(character).action_name(), None);
+}
+
+#[test]
+fn slide_ends_when_sliding_off_a_ledge() {
+    const FLOOR: Float = -3.0;
+    let mut world = MockWorld::empty();
+    world.spawn_block((-30.0, 0.0), (-5.0, 5.0), 0.0);
+    world.spawn_collider(
+        Transform::from_translation(Vector3::new(0.0, FLOOR, 0.0).f32()),
+        TnuaMockCollider::Plane,
+    );
+    let character = world.spawn_standing_character(-10.0, 0.0);
+    world.run(character, 60, |controller| {
+        controller.basis(TnuaBuiltinWalk {
+            desired_velocity: Vector3::X * 8.0,
+            ..walk()
+        });
+    });
+    let mut sliding_frames = 0;
+    let mut airborne_frames = 0;
+    for _ in 0..120 {
+        // Release the slide button for one frame after falling off the ledge, and press it again
+        // while still in the air.
+        let slide_pressed = airborne_frames != 1;
+        world.run(character, 1, |controller| {
+            controller.basis(TnuaBuiltinWalk {
+                desired_velocity: Vector3::X * 8.0,
+                ..walk()
+            });
+            if slide_pressed {
+                controller.action(slide());
+            }
+        });
+        let controller = world.controller(character);
+        if controller.is_airborne().unwrap() {
+            airborne_frames += 1;
+            // Even when the sensor already sees the floor below, the character is not on it yet.
+            assert_eq!(controller.action_name(), None);
+        } else if controller.action_name() == Some(TnuaBuiltinSlide::NAME) {
+            sliding_frames += 1;
+        }
+    }
+    assert!(0 < sliding_frames, "the character did not slide");
+    assert!(
+        0 < airborne_frames,
+        "the character did not fall off the ledge"
+    );
+    // The slide only starts again after landing.
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinSlide::NAME)
+    );
+    assert_close(
+        world.translation(character).y,
+        FLOOR + FLOAT_HEIGHT + FLOAT_OFFSET,
+        0.05,
+    );
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinWalk;
+use bevy_tnua::math::{Float, Vector3};
+use bevy_tnua_mock::TnuaMockCollider;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+const MAX_SLOPE_ANGLE: Float = 40.0;
+
+/// A world with a slope of the given angle (in degrees) that rises toward the positive X axis,
+/// and a character floating above it.
+#[allow(clippy::unnecessary_cast)]
+fn character_on_slope(angle: Float) -> (MockWorld, Entity) {
+    let mut world = MockWorld::empty();
+    world.spawn_collider(
+        Transform::from_rotation(Quat::from_rotation_z((angle as f32).to_radians())),
+        TnuaMockCollider::Plane,
+    );
+    let character = world.spawn_character(Vector3::new(0.0, FLOAT_HEIGHT, 0.0));
+    (world, character)
+}
+
+fn walk_on_slope(
+    world: &mut MockWorld,
+    character: Entity,
+    frames: usize,
+    desired_velocity: Vector3,
+) {
+    world.run(character, frames, |controller| {
+        controller.basis(TnuaBuiltinWalk {
+            desired_velocity,
+            max_slope_angle: MAX_SLOPE_ANGLE.to_radians(),
+            ..walk()
+        });
+    });
+}
+
+fn on_steep_slope(world: &MockWorld, character: Entity) -> bool {
+    let (_, state) = world
+        .controller(character)
+        .concrete_basis::<TnuaBuiltinWalk>()
+        .expect("basis is not a walk");
+    state.on_steep_slope()
+}
+
+#[test]
+fn walk_holds_the_character_on_a_walkable_slope() {
+    let (mut world, character) = character_on_slope(MAX_SLOPE_ANGLE - 10.0);
+    walk_on_slope(&mut world, character, 30, Vector3::X * 2.0);
+    assert!(!on_steep_slope(&world, character));
+    assert!(0.0 < world.velocity(character).x);
+    assert_close(
+        world.ground_proximity(character).unwrap(),
+        FLOAT_HEIGHT,
+        0.1,
+    );
+}
+
+#[test]
+fn walk_slides_down_a_slope_steeper_than_max_slope_angle() {
+    let (mut world, character) = character_on_slope(MAX_SLOPE_ANGLE + 10.0);
+    walk_on_slope(&mut world, character, 20, Vector3::ZERO);
+    assert!(on_steep_slope(&world, character));
+    // The spring does not hold the character up, so gravity pulls it down.
+    assert!(world.velocity(character).y < -1.0);
+    assert!(world.ground_proximity(character).unwrap() < FLOAT_HEIGHT - 0.5);
+}
+
+#[test]
+fn walk_cannot_move_uphill_on_a_slope_steeper_than_max_slope_angle() {
+    let (mut world, character) = character_on_slope(MAX_SLOPE_ANGLE + 10.0);
+    // Let the sensor detect the slope first - otherwise the character is considered airborne and
+    // can use its air control.
+    walk_on_slope(&mut world, character, 1, Vector3::ZERO);
+    walk_on_slope(&mut world, character, 10, Vector3::X * 2.0);
+    assert!(on_steep_slope(&world, character));
+    assert_close(world.velocity(character).x, 0.0, 0.001);
+
+    // Moving downhill is still allowed.
+    walk_on_slope(&mut world, character, 10, -Vector3::X * 2.0);
+    assert!(world.velocity(character).x < -1.0);
+}
@@ -0,0 +1,110 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinWalk;
+use bevy_tnua::math::{AdjustPrecision, Float, Vector3};
+use bevy_tnua::{TnuaProximitySensor, TnuaStepSensor};
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+const MAX_STEP_HEIGHT: Float = 0.5;
+
+fn spawn_stepping_character(world: &mut MockWorld) -> Entity {
+    let character = world.spawn_standing_character(0.0, 0.0);
+    world
+        .app
+        .world
+        .entity_mut(character)
+        .insert(TnuaStepSensor {
+            cast_origin: Vector3::new(0.0, MAX_STEP_HEIGHT - FLOAT_HEIGHT, 0.0),
+            cast_direction: Direction3d::X,
+            probe_depth: MAX_STEP_HEIGHT + 0.1,
+            ..Default::default()
+        });
+    character
+}
+
+fn stepping_walk() -> TnuaBuiltinWalk {
+    TnuaBuiltinWalk {
+        max_step_height: MAX_STEP_HEIGHT,
+        ..walk()
+    }
+}
+
+/// Walk toward a step whose edge is at `x = 2`, and return the character's height right before
+/// its center reaches the edge.
+fn height_before_the_edge(
+    world: &mut MockWorld,
+    character: Entity,
+    walk_basis: TnuaBuiltinWalk,
+) -> Float {
+    for _ in 0..240 {
+        let (step_point, step_normal) = world
+            .get::<TnuaStepSensor>(character)
+            .output
+            .as_ref()
+            .map_or((Vector3::ZERO, Vector3::ZERO), |output| {
+                (output.point, output.normal.adjust_precision())
+            });
+        world.run(character, 1, |controller| {
+            controller.basis(TnuaBuiltinWalk {
+                desired_velocity: Vector3::X,
+                step_point,
+                step_normal,
+                ..walk_basis.clone()
+            });
+        });
+        let translation = world.translation(character);
+        if 1.95 <= translation.x {
+            assert!(translation.x < 2.0, "overshot the edge");
+            return translation.y;
+        }
+    }
+    panic!("character did not reach the step");
+}
+
+#[test]
+fn walk_steps_up_onto_a_step_at_max_step_height() {
+    let mut world = MockWorld::with_ground();
+    world.spawn_block((2.0, 20.0), (-5.0, 5.0), MAX_STEP_HEIGHT - 0.01);
+    let character = spawn_stepping_character(&mut world);
+    let height = height_before_the_edge(&mut world, character, stepping_walk());
+    assert_close(height, FLOAT_HEIGHT + MAX_STEP_HEIGHT, 0.1);
+}
+
+#[test]
+fn walk_does_not_step_up_onto_a_step_higher_than_max_step_height() {
+    let mut world = MockWorld::with_ground();
+    world.spawn_block((2.0, 20.0), (-5.0, 5.0), MAX_STEP_HEIGHT + 0.2);
+    let character = spawn_stepping_character(&mut world);
+    let height = height_before_the_edge(&mut world, character, stepping_walk());
+    assert_close(height, FLOAT_HEIGHT, 0.01);
+}
+
+#[test]
+fn walk_does_not_step_up_without_max_step_height() {
+    let mut world = MockWorld::with_ground();
+    world.spawn_block((2.0, 20.0), (-5.0, 5.0), MAX_STEP_HEIGHT - 0.01);
+    let character = spawn_stepping_character(&mut world);
+    let height = height_before_the_edge(&mut world, character, walk());
+    assert_close(height, FLOAT_HEIGHT, 0.01);
+}
+
+#[test]
+fn walk_step_height_accounts_for_the_proximity_sensor_cast_origin() {
+    let mut world = MockWorld::with_ground();
+    world.spawn_block((2.0, 20.0), (-5.0, 5.0), MAX_STEP_HEIGHT - 0.01);
+    let character = spawn_stepping_character(&mut world);
+    // Cast from the character's feet instead of its center, and float the same height above the
+    // ground.
+    world.get_mut::<TnuaProximitySensor>(character).cast_origin = Vector3::new(0.0, -1.0, 0.0);
+    let height = height_before_the_edge(
+        &mut world,
+        character,
+        TnuaBuiltinWalk {
+            float_height: FLOAT_HEIGHT - 1.0,
+            ..stepping_walk()
+        },
+    );
+    assert_close(height, FLOAT_HEIGHT + MAX_STEP_HEIGHT, 0.1);
+}
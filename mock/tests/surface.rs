@@ -0,0 +1,81 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinWalk;
+use bevy_tnua::math::{Float, Vector3};
+use bevy_tnua::prelude::*;
+use bevy_tnua::TnuaSurface;
+use bevy_tnua_mock::TnuaMockCollider;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+/// A character standing on ground with the given surface.
+fn character_on_surface(surface: TnuaSurface) -> (MockWorld, Entity) {
+    let mut world = MockWorld::empty();
+    let ground = world.spawn_collider(Transform::default(), TnuaMockCollider::Plane);
+    world.app.world.entity_mut(ground).insert(surface);
+    let character = world.spawn_standing_character(0.0, 0.0);
+    (world, character)
+}
+
+fn walk_at(speed: Float) -> TnuaBuiltinWalk {
+    TnuaBuiltinWalk {
+        desired_velocity: Vector3::X * speed,
+        ..walk()
+    }
+}
+
+#[test]
+fn surface_scales_the_walk_speed() {
+    let (mut world, character) = character_on_surface(TnuaSurface {
+        max_speed_multiplier: 0.5,
+        ..Default::default()
+    });
+    world.run(character, 60, |controller| {
+        controller.basis(walk_at(4.0));
+    });
+    assert_close(world.velocity(character).x, 2.0, 0.01);
+}
+
+#[test]
+fn surface_scales_the_jump_height() {
+    let (mut world, character) = character_on_surface(TnuaSurface {
+        jump_height_multiplier: 2.0,
+        ..Default::default()
+    });
+    let mut apex = world.translation(character).y;
+    for _ in 0..180 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk());
+            controller.action(TnuaBuiltinJump {
+                height: 1.0,
+                ..Default::default()
+            });
+        });
+        apex = apex.max(world.translation(character).y);
+    }
+    assert_close(apex - FLOAT_HEIGHT, 2.0, 0.2);
+}
+
+/// The speed of a character that walked at the given speed and then stopped for a few frames.
+fn speed_after_stopping(surface: TnuaSurface) -> Float {
+    let (mut world, character) = character_on_surface(surface);
+    world.run(character, 60, |controller| {
+        controller.basis(walk_at(4.0));
+    });
+    world.run(character, 5, |controller| {
+        controller.basis(walk());
+    });
+    world.velocity(character).x
+}
+
+#[test]
+fn slippery_surface_makes_the_character_slide_before_it_stops() {
+    let regular_speed = speed_after_stopping(TnuaSurface::default());
+    let slippery_speed = speed_after_stopping(TnuaSurface {
+        friction_multiplier: 0.1,
+        ..Default::default()
+    });
+    assert_close(regular_speed, 0.0, 0.01);
+    assert!(3.0 < slippery_speed, "slippery speed is {slippery_speed}");
+}
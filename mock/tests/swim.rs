@@ -0,0 +1,71 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinSwim;
+use bevy_tnua::math::{Float, Vector3};
+use bevy_tnua::{TnuaWaterSensor, TnuaWaterVolume};
+use bevy_tnua_mock::TnuaMockCollider;
+
+use common::{assert_close, MockWorld};
+
+/// A world with deep water whose surface is at `y = 0`, and a swimming character in it.
+fn swimming_world(start_depth: Float) -> (MockWorld, Entity) {
+    let mut world = MockWorld::empty();
+    world.app.world.spawn((
+        Transform::from_xyz(0.0, -50.0, 0.0),
+        TnuaMockCollider::Cuboid {
+            half_extents: Vector3::new(100.0, 50.0, 100.0),
+        },
+        TnuaWaterVolume,
+    ));
+    let character = world.spawn_character(Vector3::new(0.0, -start_depth, 0.0));
+    world
+        .app
+        .world
+        .entity_mut(character)
+        .insert(TnuaWaterSensor::default());
+    (world, character)
+}
+
+fn swim(world: &mut MockWorld, character: Entity, frames: usize, desired_velocity: Vector3) {
+    for _ in 0..frames {
+        let water_depth = world
+            .get::<TnuaWaterSensor>(character)
+            .output
+            .as_ref()
+            .map_or(0.0, |output| output.depth);
+        world.controller_mut(character).basis(TnuaBuiltinSwim {
+            desired_velocity,
+            water_depth,
+            ..Default::default()
+        });
+        world.update();
+    }
+}
+
+#[test]
+fn swimmer_floats_at_float_depth() {
+    for start_depth in [0.2, 1.5] {
+        let (mut world, character) = swimming_world(start_depth);
+        swim(&mut world, character, 300, Vector3::ZERO);
+        let float_depth = TnuaBuiltinSwim::default().float_depth;
+        assert_close(world.translation(character).y, -float_depth, 0.02);
+        assert_close(world.velocity(character).y, 0.0, 0.02);
+    }
+}
+
+#[test]
+fn swimmer_reaches_desired_velocity() {
+    let (mut world, character) = swimming_world(0.5);
+    swim(&mut world, character, 120, Vector3::X * 3.0);
+    assert_close(world.velocity(character).x, 3.0, 0.05);
+    assert_close(world.translation(character).y, -0.5, 0.05);
+}
+
+#[test]
+fn swimmer_dives_when_moving_down() {
+    let (mut world, character) = swimming_world(0.5);
+    swim(&mut world, character, 60, Vector3::NEG_Y * 2.0);
+    assert_close(world.velocity(character).y, -2.0, 0.05);
+    assert!(world.translation(character).y < -1.5);
+}
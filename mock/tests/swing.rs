@@ -0,0 +1,87 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinSwing;
+use bevy_tnua::math::{Float, Vector3};
+use bevy_tnua::TnuaAction;
+
+use common::{walk, MockWorld};
+
+const ANCHOR: Vector3 = Vector3::new(0.0, 20.0, 0.0);
+
+fn swing() -> TnuaBuiltinSwing {
+    TnuaBuiltinSwing {
+        anchor: ANCHOR,
+        ..Default::default()
+    }
+}
+
+fn rope_length(world: &MockWorld, character: Entity) -> Float {
+    let (_, state) = world
+        .controller(character)
+        .concrete_action::<TnuaBuiltinSwing>()
+        .expect("not swinging");
+    state.rope_length()
+}
+
+#[test]
+fn swing_keeps_the_character_within_the_rope_length() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_character(Vector3::new(4.0, 17.0, 0.0));
+    let initial_distance = Vector3::new(4.0, 17.0, 0.0).distance(ANCHOR);
+    let mut min_x = Float::INFINITY;
+    for _ in 0..120 {
+        world.run(character, 1, |controller| {
+            controller.basis(walk());
+            controller.action(swing());
+        });
+        assert_eq!(
+            world.controller(character).action_name(),
+            Some(TnuaBuiltinSwing::NAME)
+        );
+        let translation = world.translation(character);
+        assert!(
+            translation.distance(ANCHOR) < initial_distance + 0.05,
+            "character got {} away from the anchor",
+            translation.distance(ANCHOR),
+        );
+        min_x = min_x.min(translation.x);
+    }
+    assert!((initial_distance - rope_length(&world, character)).abs() < 0.001);
+    // The character swung past the bottom of the arc to the other side.
+    assert!(min_x < -1.0, "character only swung to x={min_x}");
+}
+
+#[test]
+fn swing_rope_can_be_reeled_in() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_character(Vector3::new(0.0, 14.0, 0.0));
+    world.run(character, 60, |controller| {
+        controller.basis(walk());
+        controller.action(TnuaBuiltinSwing {
+            reel_speed: 2.0,
+            ..swing()
+        });
+    });
+    let rope_length = rope_length(&world, character);
+    assert!(
+        (rope_length - 4.0).abs() < 0.05,
+        "rope length is {rope_length}"
+    );
+    let distance = world.translation(character).distance(ANCHOR);
+    assert!(
+        distance < 4.1,
+        "character is {distance} away from the anchor"
+    );
+}
+
+#[test]
+fn swing_is_rejected_when_the_anchor_is_too_far() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_character(Vector3::new(0.0, 5.0, 0.0));
+    world.run(character, 1, |controller| {
+        controller.basis(walk());
+        controller.action(swing());
+    });
+    assert_eq!(world.controller(character).action_name(), None);
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use bevy_tnua::math::Vector3;
+use bevy_tnua::prelude::*;
+
+use common::{assert_close, walk, MockWorld, FLOAT_HEIGHT};
+
+#[test]
+fn walk_floats_at_float_height() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_character(Vector3::new(0.0, 3.0, 0.0));
+    world.run(character, 120, |controller| {
+        controller.basis(walk());
+    });
+    assert!(!world.controller(character).is_airborne().unwrap());
+    assert_close(world.translation(character).y, FLOAT_HEIGHT, 0.05);
+    assert_close(world.velocity(character).length(), 0.0, 0.05);
+}
+
+#[test]
+fn walk_reaches_desired_velocity() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    world.run(character, 60, |controller| {
+        controller.basis(TnuaBuiltinWalk {
+            desired_velocity: Vector3::X * 4.0,
+            ..walk()
+        });
+    });
+    let velocity = world.velocity(character);
+    assert_close(velocity.x, 4.0, 0.01);
+    assert_close(velocity.z, 0.0, 0.01);
+    assert_close(world.translation(character).y, FLOAT_HEIGHT, 0.05);
+}
+
+#[test]
+fn walk_stops_when_desired_velocity_is_zero() {
+    let mut world = MockWorld::with_ground();
+    let character = world.spawn_standing_character(0.0, 0.0);
+    world.run(character, 60, |controller| {
+        controller.basis(TnuaBuiltinWalk {
+            desired_velocity: Vector3::X * 4.0,
+            ..walk()
+        });
+    });
+    // With the default acceleration of 60, stopping from 4 m/s should take about 4/90 seconds.
+    world.run(character, 6, |controller| {
+        controller.basis(walk());
+    });
+    assert_close(world.velocity(character).x, 0.0, 0.01);
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::{TnuaBuiltinJump, TnuaBuiltinWallJump, TnuaBuiltinWallSlide};
+use bevy_tnua::math::{AdjustPrecision, Float, Vector3};
+use bevy_tnua::{TnuaAction, TnuaWallSensor};
+
+use common::{assert_close, walk, MockWorld};
+
+/// A character in the air, half a meter in front of a tall wall whose face is at `z = -2`.
+fn world_with_wall(height: Float) -> (MockWorld, Entity) {
+    let mut world = MockWorld::with_ground();
+    world.spawn_block((-5.0, 5.0), (-3.0, -2.0), 50.0);
+    let character = world.spawn_character(Vector3::new(0.0, height, -1.5));
+    world
+        .app
+        .world
+        .entity_mut(character)
+        .insert(TnuaWallSensor::default());
+    world.update();
+    (world, character)
+}
+
+fn wall_normal(world: &MockWorld, character: Entity) -> Vector3 {
+    world
+        .get::<TnuaWallSensor>(character)
+        .output
+        .as_ref()
+        .map_or(Vector3::ZERO, |output| output.normal.adjust_precision())
+}
+
+#[test]
+fn wall_sensor_detects_the_wall() {
+    let (world, character) = world_with_wall(20.0);
+    let output = world
+        .get::<TnuaWallSensor>(character)
+        .output
+        .clone()
+        .unwrap();
+    assert_close(output.proximity, 0.5, 0.01);
+    assert_close(output.normal.adjust_precision().z, 1.0, 0.001);
+}
+
+#[test]
+fn wall_slide_caps_the_fall_speed() {
+    let (mut world, character) = world_with_wall(30.0);
+    for _ in 0..120 {
+        let wall_normal = wall_normal(&world, character);
+        let mut controller = world.controller_mut(character);
+        controller.basis(walk());
+        controller.action(TnuaBuiltinWallSlide {
+            wall_normal,
+            ..Default::default()
+        });
+        world.update();
+    }
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinWallSlide::NAME)
+    );
+    let max_fall_speed = TnuaBuiltinWallSlide::default().max_fall_speed;
+    assert_close(world.velocity(character).y, -max_fall_speed, 0.01);
+}
+
+#[test]
+fn falling_without_wall_slide_is_faster() {
+    let (mut world, character) = world_with_wall(30.0);
+    world.run(character, 30, |controller| {
+        controller.basis(walk());
+    });
+    assert!(world.velocity(character).y < -10.0);
+}
+
+#[test]
+fn wall_jump_pushes_away_from_the_wall_and_up() {
+    let (mut world, character) = world_with_wall(20.0);
+    let start = world.translation(character);
+    let mut apex: Float = start.y;
+    for frame in 0..60 {
+        let wall_normal = if frame == 0 {
+            wall_normal(&world, character)
+        } else {
+            Vector3::ZERO
+        };
+        let mut controller = world.controller_mut(character);
+        controller.basis(walk());
+        controller.action(TnuaBuiltinWallJump {
+            wall_normal,
+            jump: TnuaBuiltinJump {
+                height: 2.0,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        world.update();
+        if frame == 0 {
+            let push_speed = TnuaBuiltinWallJump::default().push_speed;
+            assert_close(world.velocity(character).z, push_speed, 0.01);
+        }
+        apex = apex.max(world.translation(character).y);
+    }
+    assert_close(apex - start.y, 2.0, 0.2);
+    assert!(world.translation(character).z > start.z + 1.0);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy_tnua::builtins::TnuaBuiltinWallRun;
+use bevy_tnua::math::{AdjustPrecision, Vector3};
+use bevy_tnua::{TnuaAction, TnuaWallSensor};
+
+use common::{assert_close, walk, MockWorld};
+
+/// A character in the air, moving along a long wall whose face is at `z = -2`.
+fn world_with_wall() -> (MockWorld, Entity) {
+    let mut world = MockWorld::with_ground();
+    world.spawn_block((-100.0, 100.0), (-3.0, -2.0), 50.0);
+    let character = world.spawn_character(Vector3::new(0.0, 20.0, -1.5));
+    world
+        .app
+        .world
+        .entity_mut(character)
+        .insert(TnuaWallSensor::default());
+    // Wait for the coyote time to pass, so that the character will be considered airborne.
+    world.run(character, 30, |controller| {
+        controller.basis(walk());
+    });
+    world.set_velocity(character, Vector3::X * 5.0);
+    (world, character)
+}
+
+fn wall_run(world: &mut MockWorld, character: Entity) {
+    let wall_normal = world
+        .get::<TnuaWallSensor>(character)
+        .output
+        .as_ref()
+        .map_or(Vector3::ZERO, |output| output.normal.adjust_precision());
+    let mut controller = world.controller_mut(character);
+    controller.basis(walk());
+    controller.action(TnuaBuiltinWallRun {
+        wall_normal,
+        ..Default::default()
+    });
+    world.update();
+}
+
+#[test]
+fn wall_run_moves_along_the_wall_with_reduced_gravity() {
+    let (mut world, character) = world_with_wall();
+    wall_run(&mut world, character);
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinWallRun::NAME)
+    );
+    for _ in 0..30 {
+        wall_run(&mut world, character);
+    }
+    let TnuaBuiltinWallRun {
+        speed,
+        gravity_factor,
+        ..
+    } = TnuaBuiltinWallRun::default();
+    let velocity = world.velocity(character);
+    assert_close(velocity.x, speed, 0.01);
+    assert_close(velocity.z, 0.0, 0.01);
+    assert_close(velocity.y, -gravity_factor * 9.81 * 0.5, 0.1);
+    assert_close(world.translation(character).z, -1.5, 0.01);
+}
+
+#[test]
+fn wall_run_ends_after_max_duration() {
+    let (mut world, character) = world_with_wall();
+    let max_duration = TnuaBuiltinWallRun::default().max_duration;
+    let frames = (max_duration * 60.0) as usize;
+    for _ in 0..frames - 5 {
+        wall_run(&mut world, character);
+    }
+    assert_eq!(
+        world.controller(character).action_name(),
+        Some(TnuaBuiltinWallRun::NAME)
+    );
+    for _ in 0..10 {
+        wall_run(&mut world, character);
+    }
+    assert_eq!(world.controller(character).action_name(), None);
+}
+
+#[test]
+fn wall_run_does_not_start_when_too_slow() {
+    let (mut world, character) = world_with_wall();
+    world.set_velocity(character, Vector3::X * 2.0);
+    for _ in 0..10 {
+        wall_run(&mut world, character);
+    }
+    assert_eq!(world.controller(character).action_name(), None);
+}
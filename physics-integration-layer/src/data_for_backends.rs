@@ -9,7 +9,8 @@ use bevy::prelude::*;
 ///
 /// This component is not mandatory - if omitted, Tnua will just assume it is enabled for that
 /// entity.
-#[derive(Component, Default, Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Component, Default, Debug, PartialEq, Eq, Clone, Copy, Reflect)]
+#[reflect(Component, Default)]
 pub enum TnuaToggle {
     /// Do not update the sensors, and do not apply forces from the motor.
     ///
@@ -34,7 +35,8 @@ pub enum TnuaToggle {
 /// like velocity are dependent on the physics engine. The physics backend is responsible for
 /// updating this component from the physics engine during
 /// [`TnuaPipelineStages::Sensors`](crate::TnuaPipelineStages::Sensors).
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
 pub struct TnuaRigidBodyTracker {
     pub translation: Vector3,
     pub rotation: Quaternion,
@@ -62,7 +64,8 @@ impl Default for TnuaRigidBodyTracker {
 /// The physics backend is responsible for updating this component from the physics engine during
 /// [`TnuaPipelineStages::Sensors`](crate::TnuaPipelineStages::Sensors), usually by casting a ray
 /// or a shape in the `cast_direction`.
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
 pub struct TnuaProximitySensor {
     /// The cast origin in the entity's coord system.
     pub cast_origin: Vector3,
@@ -106,7 +109,7 @@ impl Default for TnuaProximitySensor {
 }
 
 /// Information from [`TnuaProximitySensor`] that have detected another collider.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Reflect)]
 pub struct TnuaProximitySensorOutput {
     /// The entity of the collider detected by the ray.
     pub entity: Entity,
@@ -124,7 +127,7 @@ pub struct TnuaProximitySensorOutput {
 }
 
 /// Represents a change to velocity (linear or angular)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Reflect)]
 pub struct TnuaVelChange {
     // The part of the velocity change that gets multiplied by the frame duration.
     //
@@ -195,7 +198,8 @@ impl AddAssign for TnuaVelChange {
 ///
 /// This documentation uses the term "forces", but in fact these numbers ignore mass and are
 /// applied directly to the velocity.
-#[derive(Component, Default, Debug)]
+#[derive(Component, Default, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
 pub struct TnuaMotor {
     /// How much velocity to add to the rigid body in the current frame.
     pub lin: TnuaVelChange,
@@ -215,7 +219,8 @@ pub struct TnuaMotor {
 /// See <https://github.com/idanarye/bevy-tnua/wiki/Jump-fall-Through-Platforms>
 ///
 /// See `TnuaSimpleFallThroughPlatformsHelper`.
-#[derive(Component, Default, Debug)]
+#[derive(Component, Default, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
 pub struct TnuaGhostSensor(pub Vec<TnuaProximitySensorOutput>);
 
 impl TnuaGhostSensor {
@@ -233,5 +238,223 @@ impl TnuaGhostSensor {
 /// See <https://github.com/idanarye/bevy-tnua/wiki/Jump-fall-Through-Platforms>
 ///
 /// See `TnuaSimpleFallThroughPlatformsHelper`.
-#[derive(Component, Default, Debug)]
+#[derive(Component, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
 pub struct TnuaGhostPlatform;
+
+/// A marker for water volumes - colliders the character can swim in.
+///
+/// The collider of a water volume should be a sensor, so that the character could enter it and so
+/// that [`TnuaProximitySensor`] would ignore it. Only [`TnuaWaterSensor`] detects water volumes.
+#[derive(Component, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct TnuaWaterVolume;
+
+/// Detects how deep the entity is inside a [`TnuaWaterVolume`].
+///
+/// The physics backend is responsible for updating this component from the physics engine during
+/// [`TnuaPipelineStages::Sensors`](crate::TnuaPipelineStages::Sensors), by casting a ray from
+/// [`cast_range`](Self::cast_range) above the entity's origin, in the opposite direction of
+/// [`up`](Self::up), until the entity's origin. The ray should be solid (so that it hits with a
+/// proximity of `0.0` if it starts inside the water volume) and should only hit water volumes.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct TnuaWaterSensor {
+    /// How far above the entity's origin to look for the water surface.
+    ///
+    /// When the water surface is further than that, the [depth](TnuaWaterSensorOutput::depth)
+    /// will be reported as `cast_range`.
+    pub cast_range: Float,
+    /// The direction (in the world space) considered as upward.
+    pub up: Direction3d,
+    pub output: Option<TnuaWaterSensorOutput>,
+}
+
+impl Default for TnuaWaterSensor {
+    fn default() -> Self {
+        Self {
+            cast_range: 2.0,
+            up: Direction3d::Y,
+            output: None,
+        }
+    }
+}
+
+/// Information from [`TnuaWaterSensor`] that have detected a water volume.
+#[derive(Debug, Clone, Reflect)]
+pub struct TnuaWaterSensorOutput {
+    /// The entity of the water volume.
+    pub entity: Entity,
+    /// How far the entity's origin is below the water surface.
+    ///
+    /// This is `cast_range` minus the proximity of the cast hit.
+    pub depth: Float,
+}
+
+/// Detects a wall next to the entity, for wall-based actions like wall slides and wall jumps.
+///
+/// The physics backend is responsible for updating this component from the physics engine during
+/// [`TnuaPipelineStages::Sensors`](crate::TnuaPipelineStages::Sensors), by casting a ray from the
+/// `cast_origin` in the `cast_direction`. Like [`TnuaProximitySensor`], the cast should ignore the
+/// owner entity's collider, sensor colliders, colliders that do not physically interact with the
+/// character's collider, and [ghost platforms](TnuaGhostPlatform).
+///
+/// Unlike [`TnuaProximitySensor`], Tnua does not update any of the fields of this sensor. The
+/// control system is expected to point it at the direction the character moves toward (or faces).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct TnuaWallSensor {
+    /// The cast origin in the entity's coord system.
+    pub cast_origin: Vector3,
+    /// The direction in world coord system (unmodified by the entity's transform)
+    pub cast_direction: Direction3d,
+    /// How far from the cast origin to look for walls.
+    pub cast_range: Float,
+    pub output: Option<TnuaWallSensorOutput>,
+}
+
+impl Default for TnuaWallSensor {
+    fn default() -> Self {
+        Self {
+            cast_origin: Vector3::ZERO,
+            cast_direction: Direction3d::NEG_Z,
+            cast_range: 1.0,
+            output: None,
+        }
+    }
+}
+
+/// Information from [`TnuaWallSensor`] that have detected a wall.
+#[derive(Debug, Clone, Reflect)]
+pub struct TnuaWallSensorOutput {
+    /// The entity of the wall's collider.
+    pub entity: Entity,
+    /// The distance of the wall from the cast origin.
+    pub proximity: Float,
+    /// The normal of the wall's surface, pointing toward the cast origin.
+    pub normal: Direction3d,
+}
+
+/// Detects a ledge in front of the entity - an edge the character can grab and hang from.
+///
+/// The physics backend is responsible for updating this component from the physics engine during
+/// [`TnuaPipelineStages::Sensors`](crate::TnuaPipelineStages::Sensors), with up to three ray
+/// casts:
+///
+/// 1. A forward cast from the `cast_origin` in the `cast_direction`, up to the `reach`. If it hits
+///    anything there is no ledge - there is a wall in front of the entity.
+/// 2. A downward cast (in the opposite direction of `up`) from `reach` in front of the
+///    `cast_origin`, up to the `probe_depth`. If it hits, that's the top of the ledge. The cast
+///    should be solid - if it starts inside a collider there is no room above the ledge, and the
+///    sensor should not detect it.
+/// 3. If the `headroom_range` is farther above the top of the ledge than the start of the
+///    downward cast, an upward cast from the start of the downward cast, to find the
+///    [`headroom`](TnuaLedgeSensorOutput::headroom) above the ledge.
+///
+/// All the casts should ignore the same colliders [`TnuaWallSensor`] ignores. The `cast_origin`
+/// should be at about the height of the character's chest or hands.
+///
+/// Like [`TnuaWallSensor`], Tnua does not update any of the fields of this sensor.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct TnuaLedgeSensor {
+    /// The cast origin in the entity's coord system.
+    pub cast_origin: Vector3,
+    /// The direction in world coord system (unmodified by the entity's transform)
+    pub cast_direction: Direction3d,
+    /// The direction (in the world space) considered as upward.
+    pub up: Direction3d,
+    /// The range of the forward cast, and the distance in front of the cast origin the downward
+    /// cast starts from.
+    pub reach: Float,
+    /// The range of the downward cast - how far below the cast origin the top of the ledge can be.
+    pub probe_depth: Float,
+    /// How far above the top of the ledge to look for a ceiling.
+    ///
+    /// Set this to the height of the character to check if it has room to get onto the ledge.
+    /// When zero, the [`headroom`](TnuaLedgeSensorOutput::headroom) is not checked.
+    pub headroom_range: Float,
+    pub output: Option<TnuaLedgeSensorOutput>,
+}
+
+impl Default for TnuaLedgeSensor {
+    fn default() -> Self {
+        Self {
+            cast_origin: Vector3::ZERO,
+            cast_direction: Direction3d::NEG_Z,
+            up: Direction3d::Y,
+            reach: 1.0,
+            probe_depth: 0.5,
+            headroom_range: 0.0,
+            output: None,
+        }
+    }
+}
+
+/// Information from [`TnuaLedgeSensor`] that have detected a ledge.
+#[derive(Debug, Clone, Reflect)]
+pub struct TnuaLedgeSensorOutput {
+    /// The entity of the ledge's collider.
+    pub entity: Entity,
+    /// The point (in world coord system) where the downward cast hit the top of the ledge.
+    pub point: Vector3,
+    /// The normal of the top of the ledge.
+    pub normal: Direction3d,
+    /// The free space above the top of the ledge, or [`Float::INFINITY`] if there is no ceiling
+    /// within the sensor's [`headroom_range`](TnuaLedgeSensor::headroom_range).
+    pub headroom: Float,
+}
+
+/// Detects a step in front of the entity that the character can walk up onto.
+///
+/// The physics backend is responsible for updating this component from the physics engine during
+/// [`TnuaPipelineStages::Sensors`](crate::TnuaPipelineStages::Sensors), by casting a ray downward
+/// (in the opposite direction of `up`) from `reach` in front of the `cast_origin`, up to the
+/// `probe_depth`. If it hits, that's the top of the step. The cast should be solid - if it starts
+/// inside a collider the step is too high, and the sensor should not detect it. The cast should
+/// ignore the same colliders [`TnuaWallSensor`] ignores.
+///
+/// The `cast_origin` should be at the height of the highest step the character can walk up, and
+/// the `probe_depth` should reach a bit below the character's feet.
+///
+/// Like [`TnuaWallSensor`], Tnua does not update any of the fields of this sensor. The control
+/// system is expected to point it at the direction the character moves toward.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+pub struct TnuaStepSensor {
+    /// The cast origin in the entity's coord system.
+    pub cast_origin: Vector3,
+    /// The direction in world coord system (unmodified by the entity's transform)
+    pub cast_direction: Direction3d,
+    /// The direction (in the world space) considered as upward.
+    pub up: Direction3d,
+    /// The distance in front of the cast origin the downward cast starts from.
+    pub reach: Float,
+    /// The range of the downward cast - how far below the cast origin to look for the step.
+    pub probe_depth: Float,
+    pub output: Option<TnuaStepSensorOutput>,
+}
+
+impl Default for TnuaStepSensor {
+    fn default() -> Self {
+        Self {
+            cast_origin: Vector3::ZERO,
+            cast_direction: Direction3d::NEG_Z,
+            up: Direction3d::Y,
+            reach: 0.5,
+            probe_depth: 1.0,
+            output: None,
+        }
+    }
+}
+
+/// Information from [`TnuaStepSensor`] that have detected a step.
+#[derive(Debug, Clone, Reflect)]
+pub struct TnuaStepSensorOutput {
+    /// The entity of the step's collider.
+    pub entity: Entity,
+    /// The point (in world coord system) where the downward cast hit the top of the step.
+    pub point: Vector3,
+    /// The normal of the top of the step.
+    pub normal: Direction3d,
+}
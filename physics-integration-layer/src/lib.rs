@@ -33,6 +33,18 @@
 //!       physics engine. The name of that component should be
 //!       `Tnua<physics-engine-name>SensorShape`.
 //!
+//!   * [`TnuaWaterSensor`](data_for_backends::TnuaWaterSensor) with the depth of the entity inside
+//!     a [`TnuaWaterVolume`](data_for_backends::TnuaWaterVolume), by casting a ray downward from
+//!     above the entity (see the documentation of `TnuaWaterSensor` for details)
+//!   * [`TnuaWallSensor`](data_for_backends::TnuaWallSensor) with the first tangible, non-ghost
+//!     collider within range of a ray cast in its (world space) cast direction.
+//!   * [`TnuaLedgeSensor`](data_for_backends::TnuaLedgeSensor) with the top of a ledge in front
+//!     of the entity, detected with a forward ray cast and a downward ray cast (see the
+//!     documentation of `TnuaLedgeSensor` for details)
+//!   * [`TnuaStepSensor`](data_for_backends::TnuaStepSensor) with the top of a step in front of
+//!     the entity, detected with a downward ray cast (see the documentation of `TnuaStepSensor`
+//!     for details)
+//!
 //!   The integration crate may update all these components in one system or multiple systems as it
 //!   sees fit.
 //!
@@ -7,6 +7,7 @@
 //! * Optionally: Add [`TnuaRapier2dSensorShape`] to the sensor entities. This means the entity of
 //!   the characters controlled by Tnua, but also other things like the entity generated by
 //!   `TnuaCrouchEnforcer`, that can be affected with a closure.
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
 use bevy::utils::HashSet;
 use bevy_rapier2d::prelude::*;
@@ -16,36 +17,67 @@ use bevy_rapier2d::rapier::prelude::InteractionGroups;
 use bevy_tnua_physics_integration_layer::data_for_backends::TnuaGhostPlatform;
 use bevy_tnua_physics_integration_layer::data_for_backends::TnuaGhostSensor;
 use bevy_tnua_physics_integration_layer::data_for_backends::TnuaToggle;
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaLedgeSensor, TnuaLedgeSensorOutput, TnuaStepSensor, TnuaStepSensorOutput,
+};
 use bevy_tnua_physics_integration_layer::data_for_backends::{
     TnuaMotor, TnuaProximitySensor, TnuaProximitySensorOutput, TnuaRigidBodyTracker,
 };
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaWallSensor, TnuaWallSensorOutput,
+};
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaWaterSensor, TnuaWaterSensorOutput, TnuaWaterVolume,
+};
 use bevy_tnua_physics_integration_layer::subservient_sensors::TnuaSubservientSensor;
 use bevy_tnua_physics_integration_layer::TnuaPipelineStages;
 use bevy_tnua_physics_integration_layer::TnuaSystemSet;
 
 /// Add this plugin to use bevy_rapier2d as a physics backend.
 ///
-/// This plugin should be used in addition to `TnuaControllerPlugin`.
-pub struct TnuaRapier2dPlugin;
+/// This plugin should be used in addition to `TnuaControllerPlugin`, and both plugins must use
+/// the same schedule.
+pub struct TnuaRapier2dPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl TnuaRapier2dPlugin {
+    /// Create the plugin, running its systems in the given schedule.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
+impl Default for TnuaRapier2dPlugin {
+    fn default() -> Self {
+        Self::new(Update)
+    }
+}
 
 impl Plugin for TnuaRapier2dPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(
-            Update,
+            self.schedule,
             TnuaSystemSet.run_if(|rapier_config: Res<RapierConfiguration>| {
                 rapier_config.physics_pipeline_active
             }),
         );
         app.add_systems(
-            Update,
+            self.schedule,
             (
                 update_rigid_body_trackers_system,
                 update_proximity_sensors_system,
+                update_water_sensors_system,
+                update_wall_sensors_system,
+                update_ledge_sensors_system,
+                update_step_sensors_system,
             )
                 .in_set(TnuaPipelineStages::Sensors),
         );
         app.add_systems(
-            Update,
+            self.schedule,
             apply_motors_system.in_set(TnuaPipelineStages::Motors),
         );
     }
@@ -98,6 +130,54 @@ fn get_collider(
     //if let Some(owner_collider) = rapier_context.entity2collider().get(&owner_entity).and_then(|handle| rapier_context.colliders.get(*handle)) {
 }
 
+/// Create a query filter for the sensors of `owner_entity`, based on the collision groups of its
+/// collider, and get the solver groups the colliders the sensors detect must interact with.
+fn owner_query_filter<'a>(
+    rapier_context: &RapierContext,
+    owner_entity: Entity,
+) -> (QueryFilter<'a>, InteractionGroups) {
+    let mut query_filter = QueryFilter::new().exclude_rigid_body(owner_entity);
+    let owner_solver_groups: InteractionGroups;
+    if let Some(owner_collider) = get_collider(rapier_context, owner_entity) {
+        let collision_groups = owner_collider.collision_groups();
+        query_filter.groups = Some(CollisionGroups {
+            memberships: Group::from_bits_truncate(collision_groups.memberships.bits()),
+            filters: Group::from_bits_truncate(collision_groups.filter.bits()),
+        });
+        owner_solver_groups = owner_collider.solver_groups();
+    } else {
+        owner_solver_groups = InteractionGroups::all();
+    }
+    (query_filter, owner_solver_groups)
+}
+
+/// Create a query filter for the sensors of `owner_entity` that only detect solid colliders (unlike
+/// the water sensor), and the predicate to use with it.
+///
+/// The predicate ignores ghost platforms, sensor colliders, and colliders that the owner does not
+/// interact with.
+fn sensor_query_filter<'a, 'b>(
+    rapier_context: &'a RapierContext,
+    ghost_platforms_query: &'a Query<'a, 'a, (), With<TnuaGhostPlatform>>,
+    owner_entity: Entity,
+) -> (QueryFilter<'b>, impl 'a + Fn(Entity) -> bool) {
+    let (query_filter, owner_solver_groups) = owner_query_filter(rapier_context, owner_entity);
+    let predicate = move |other_entity: Entity| {
+        if ghost_platforms_query.contains(other_entity) {
+            return false;
+        }
+        if let Some(other_collider) = get_collider(rapier_context, other_entity) {
+            if !other_collider.solver_groups().test(owner_solver_groups)
+                || other_collider.is_sensor()
+            {
+                return false;
+            }
+        }
+        true
+    };
+    (query_filter, predicate)
+}
+
 #[allow(clippy::type_complexity)]
 fn update_proximity_sensors_system(
     rapier_context: Res<RapierContext>,
@@ -147,19 +227,8 @@ fn update_proximity_sensors_system(
                 owner_entity
             };
 
-            let mut query_filter = QueryFilter::new().exclude_rigid_body(owner_entity);
-            let owner_solver_groups: InteractionGroups;
-
-            if let Some(owner_collider) = get_collider(&rapier_context, owner_entity) {
-                let collision_groups = owner_collider.collision_groups();
-                query_filter.groups = Some(CollisionGroups {
-                    memberships: Group::from_bits_truncate(collision_groups.memberships.bits()),
-                    filters: Group::from_bits_truncate(collision_groups.filter.bits()),
-                });
-                owner_solver_groups = owner_collider.solver_groups();
-            } else {
-                owner_solver_groups = InteractionGroups::all();
-            }
+            let (query_filter, owner_solver_groups) =
+                owner_query_filter(&rapier_context, owner_entity);
 
             let mut already_visited_ghost_entities = HashSet::<Entity>::default();
 
@@ -304,6 +373,188 @@ fn update_proximity_sensors_system(
     );
 }
 
+fn update_water_sensors_system(
+    rapier_context: Res<RapierContext>,
+    mut query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut TnuaWaterSensor,
+        Option<&TnuaToggle>,
+    )>,
+    water_volumes_query: Query<(), With<TnuaWaterVolume>>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let predicate = |other_entity: Entity| water_volumes_query.contains(other_entity);
+        let query_filter = QueryFilter::new()
+            .exclude_rigid_body(owner_entity)
+            .predicate(&predicate);
+        sensor.output = rapier_context
+            .cast_ray(
+                (transform.translation() + sensor.cast_range * *sensor.up).truncate(),
+                -sensor.up.truncate(),
+                sensor.cast_range,
+                true,
+                query_filter,
+            )
+            .map(|(entity, proximity)| TnuaWaterSensorOutput {
+                entity,
+                depth: sensor.cast_range - proximity,
+            });
+    }
+}
+
+fn update_wall_sensors_system(
+    rapier_context: Res<RapierContext>,
+    mut query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut TnuaWallSensor,
+        Option<&TnuaToggle>,
+    )>,
+    ghost_platforms_query: Query<(), With<TnuaGhostPlatform>>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.transform_point(sensor.cast_origin);
+        let cast_direction = sensor.cast_direction;
+
+        let (query_filter, predicate) =
+            sensor_query_filter(&rapier_context, &ghost_platforms_query, owner_entity);
+
+        sensor.output = rapier_context
+            .cast_ray_and_get_normal(
+                cast_origin.truncate(),
+                sensor.cast_direction.truncate(),
+                sensor.cast_range,
+                true,
+                query_filter.predicate(&predicate),
+            )
+            .map(|(entity, intersection)| TnuaWallSensorOutput {
+                entity,
+                proximity: intersection.toi,
+                normal: Direction3d::new(intersection.normal.extend(0.0))
+                    .unwrap_or(-cast_direction),
+            });
+    }
+}
+
+fn update_ledge_sensors_system(
+    rapier_context: Res<RapierContext>,
+    mut query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut TnuaLedgeSensor,
+        Option<&TnuaToggle>,
+    )>,
+    ghost_platforms_query: Query<(), With<TnuaGhostPlatform>>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.transform_point(sensor.cast_origin);
+
+        let (query_filter, predicate) =
+            sensor_query_filter(&rapier_context, &ghost_platforms_query, owner_entity);
+
+        let forward_hit = rapier_context.cast_ray(
+            cast_origin.truncate(),
+            sensor.cast_direction.truncate(),
+            sensor.reach,
+            true,
+            query_filter.predicate(&predicate),
+        );
+        if forward_hit.is_some() {
+            sensor.output = None;
+            continue;
+        }
+
+        let probe_origin = cast_origin + sensor.reach * *sensor.cast_direction;
+        let up = sensor.up;
+        sensor.output = rapier_context
+            .cast_ray_and_get_normal(
+                probe_origin.truncate(),
+                -up.truncate(),
+                sensor.probe_depth,
+                true,
+                query_filter.predicate(&predicate),
+            )
+            .filter(|(_, intersection)| 0.0 < intersection.toi)
+            .map(|(entity, intersection)| {
+                let headroom = if intersection.toi < sensor.headroom_range {
+                    rapier_context
+                        .cast_ray(
+                            probe_origin.truncate(),
+                            up.truncate(),
+                            sensor.headroom_range - intersection.toi,
+                            true,
+                            query_filter.predicate(&predicate),
+                        )
+                        .map_or(f32::INFINITY, |(_, toi)| intersection.toi + toi)
+                } else {
+                    f32::INFINITY
+                };
+                TnuaLedgeSensorOutput {
+                    entity,
+                    point: probe_origin - intersection.toi * *up,
+                    normal: Direction3d::new(intersection.normal.extend(0.0)).unwrap_or(up),
+                    headroom,
+                }
+            });
+    }
+}
+
+fn update_step_sensors_system(
+    rapier_context: Res<RapierContext>,
+    mut query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut TnuaStepSensor,
+        Option<&TnuaToggle>,
+    )>,
+    ghost_platforms_query: Query<(), With<TnuaGhostPlatform>>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.transform_point(sensor.cast_origin);
+
+        let (query_filter, predicate) =
+            sensor_query_filter(&rapier_context, &ghost_platforms_query, owner_entity);
+
+        let probe_origin = cast_origin + sensor.reach * *sensor.cast_direction;
+        let up = sensor.up;
+        sensor.output = rapier_context
+            .cast_ray_and_get_normal(
+                probe_origin.truncate(),
+                -up.truncate(),
+                sensor.probe_depth,
+                true,
+                query_filter.predicate(&predicate),
+            )
+            .filter(|(_, intersection)| 0.0 < intersection.toi)
+            .map(|(entity, intersection)| TnuaStepSensorOutput {
+                entity,
+                point: probe_origin - intersection.toi * *up,
+                normal: Direction3d::new(intersection.normal.extend(0.0)).unwrap_or(up),
+            });
+    }
+}
+
 fn apply_motors_system(
     mut query: Query<(
         &TnuaMotor,
@@ -4,7 +4,7 @@ use bevy_tnua_physics_integration_layer::math::{Float, Vector3};
 
 use std::any::Any;
 
-use crate::{TnuaMotor, TnuaProximitySensor, TnuaRigidBodyTracker};
+use crate::{TnuaMotor, TnuaProximitySensor, TnuaRigidBodyTracker, TnuaSurface};
 
 /// Various data passed to [`TnuaBasis::apply`].
 pub struct TnuaBasisContext<'a> {
@@ -16,6 +16,10 @@ pub struct TnuaBasisContext<'a> {
 
     /// A sensor that tracks the distance of the character's center from the ground.
     pub proximity_sensor: &'a TnuaProximitySensor,
+
+    /// The movement modifiers of the ground the proximity sensor detects, if it has a
+    /// [`TnuaSurface`].
+    pub surface: Option<&'a TnuaSurface>,
 }
 
 /// The main movement command of a character.
@@ -29,7 +33,7 @@ pub struct TnuaBasisContext<'a> {
 /// overwritten each frame by the controller system of the game code. Configuration is considered
 /// as part of the input. If the basis needs to persist data between frames it must keep it in its
 /// [state](Self::State).
-pub trait TnuaBasis: 'static + Send + Sync {
+pub trait TnuaBasis: 'static + Send + Sync + Clone {
     /// The default name of the basis.
     ///
     /// [Once `type_name` becomes `const`](https://github.com/rust-lang/rust/issues/63084), this
@@ -47,7 +51,10 @@ pub trait TnuaBasis: 'static + Send + Sync {
     ///
     /// 3. Inspect the basis from game code systems, like an animation controlling system that
     ///    needs to know which animation to play based on the basis' current state.
-    type State: Default + Send + Sync;
+    ///
+    /// The state must be [`Clone`] so that it can be included in a
+    /// [`TnuaControllerSnapshot`](crate::controller::TnuaControllerSnapshot).
+    type State: Default + Clone + Send + Sync;
 
     /// This is where the basis affects the character's motion.
     ///
@@ -93,6 +100,18 @@ pub trait TnuaBasis: 'static + Send + Sync {
     /// This is a query method, used by the action to determine what the basis thinks.
     fn is_airborne(&self, state: &Self::State) -> bool;
 
+    /// A velocity that jumps should add to the character, on top of the upward jump, when
+    /// jumping from this basis.
+    ///
+    /// Bases that attach the character to a surface (like
+    /// [`TnuaBuiltinClimb`](crate::builtins::TnuaBuiltinClimb)) use this to push the character
+    /// away from it. Other bases can keep the default, which does not push the character.
+    ///
+    /// This is a query method, used by the action to determine what the basis thinks.
+    fn jump_push_velocity(&self, _state: &Self::State) -> Vector3 {
+        Vector3::ZERO
+    }
+
     /// If the basis is at coyote time - finish the coyote time.
     ///
     /// This will be called automatically by Tnua, if the controller runs an action that  [violated
@@ -135,8 +154,14 @@ pub trait DynamicBasis: Send + Sync + Any + 'static {
     /// Dynamically invokes [`TnuaBasis::is_airborne`].
     fn is_airborne(&self) -> bool;
 
+    /// Dynamically invokes [`TnuaBasis::jump_push_velocity`].
+    fn jump_push_velocity(&self) -> Vector3;
+
     #[doc(hidden)]
     fn violate_coyote_time(&mut self);
+
+    #[doc(hidden)]
+    fn boxed_clone(&self) -> Box<dyn DynamicBasis>;
 }
 
 pub(crate) struct BoxableBasis<B: TnuaBasis> {
@@ -194,12 +219,24 @@ impl<B: TnuaBasis> DynamicBasis for BoxableBasis<B> {
         self.input.is_airborne(&self.state)
     }
 
+    fn jump_push_velocity(&self) -> Vector3 {
+        self.input.jump_push_velocity(&self.state)
+    }
+
     fn violate_coyote_time(&mut self) {
         self.input.violate_coyote_time(&mut self.state)
     }
+
+    fn boxed_clone(&self) -> Box<dyn DynamicBasis> {
+        Box::new(Self {
+            input: self.input.clone(),
+            state: self.state.clone(),
+        })
+    }
 }
 
 /// Various data passed to [`TnuaAction::apply`].
+#[derive(Clone, Copy)]
 pub struct TnuaActionContext<'a> {
     /// The duration of the current frame.
     pub frame_duration: Float,
@@ -210,6 +247,10 @@ pub struct TnuaActionContext<'a> {
     /// A sensor that tracks the distance of the character's center from the ground.
     pub proximity_sensor: &'a TnuaProximitySensor,
 
+    /// The movement modifiers of the ground the proximity sensor detects, if it has a
+    /// [`TnuaSurface`].
+    pub surface: Option<&'a TnuaSurface>,
+
     /// An accessor to the currently active basis.
     pub basis: &'a dyn DynamicBasis,
 }
@@ -232,6 +273,7 @@ impl<'a> TnuaActionContext<'a> {
             frame_duration: self.frame_duration,
             tracker: self.tracker,
             proximity_sensor: self.proximity_sensor,
+            surface: self.surface,
         }
     }
 }
@@ -366,7 +408,7 @@ pub enum TnuaActionInitiationDirective {
 /// overwritten each frame by the controller system of the game code - although unlike basis the
 /// input will probably be the exact same. Configuration is considered as part of the input. If the
 /// action needs to persist data between frames it must keep it in its [state](Self::State).
-pub trait TnuaAction: 'static + Send + Sync {
+pub trait TnuaAction: 'static + Send + Sync + Clone {
     /// The default name of the action.
     ///
     /// [Once `type_name` becomes `const`](https://github.com/rust-lang/rust/issues/63084), this
@@ -384,7 +426,10 @@ pub trait TnuaAction: 'static + Send + Sync {
     ///
     /// 3. Inspect the action from game code systems, like an animation controlling system that
     ///    needs to know which animation to play based on the action's current state.
-    type State: Default + Send + Sync;
+    ///
+    /// The state must be [`Clone`] so that it can be included in a
+    /// [`TnuaControllerSnapshot`](crate::controller::TnuaControllerSnapshot).
+    type State: Default + Clone + Send + Sync;
 
     /// Set this to true for actions that may launch the character into the air.
     const VIOLATES_COYOTE_TIME: bool;
@@ -447,6 +492,7 @@ pub trait DynamicAction: Send + Sync + Any + 'static {
         being_fed_for: &Stopwatch,
     ) -> TnuaActionInitiationDirective;
     fn violates_coyote_time(&self) -> bool;
+    fn boxed_clone(&self) -> Box<dyn DynamicAction>;
 }
 
 pub(crate) struct BoxableAction<A: TnuaAction> {
@@ -497,4 +543,11 @@ impl<A: TnuaAction> DynamicAction for BoxableAction<A> {
     fn violates_coyote_time(&self) -> bool {
         A::VIOLATES_COYOTE_TIME
     }
+
+    fn boxed_clone(&self) -> Box<dyn DynamicAction> {
+        Box::new(Self {
+            input: self.input.clone(),
+            state: self.state.clone(),
+        })
+    }
 }
@@ -0,0 +1,235 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor, TnuaVelChange,
+};
+
+use super::TnuaBuiltinJumpState;
+
+/// A jump [action](TnuaAction) that is charged while the jump button is held, and fires when it
+/// is released.
+///
+/// The action must be fed for as long as the player holds the jump button. While it is fed the
+/// character charges the jump on the ground (optionally lowering itself, like
+/// [`TnuaBuiltinCrouch`](crate::builtins::TnuaBuiltinCrouch) does, with
+/// [`float_offset`](Self::float_offset)), and once it is no longer fed the character jumps. The
+/// height of the jump is interpolated between [`min_height`](Self::min_height) and
+/// [`max_height`](Self::max_height) based on how long the jump was charged, and the progress of
+/// the charge can be read from [`TnuaBuiltinChargeJumpState::charge_progress`] (e.g. for
+/// displaying it in the UI).
+///
+/// The jump itself is done by [`jump`](Self::jump), whose [`height`](TnuaBuiltinJump::height) is
+/// ignored. Since the button is already released when the jump starts, the jump is never
+/// shortened by [`shorten_extra_gravity`](TnuaBuiltinJump::shorten_extra_gravity).
+///
+/// If the character leaves the ground while charging, the charge is lost.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinChargeJump {
+    /// The height of the jump when it is released immediately.
+    pub min_height: Float,
+
+    /// The height of the jump when it is fully charged.
+    pub max_height: Float,
+
+    /// The time, in seconds, it takes to fully charge the jump.
+    pub full_charge_duration: Float,
+
+    /// Controls how low the character will be while charging the jump, compared to its regular
+    /// float offset while standing.
+    ///
+    /// Leave this at 0.0 to not lower the character. Other values only work when
+    /// [`TnuaBuiltinWalk`](crate::builtins::TnuaBuiltinWalk) is the [basis](crate::TnuaBasis).
+    ///
+    /// See [`TnuaBuiltinCrouch::float_offset`](crate::builtins::TnuaBuiltinCrouch::float_offset).
+    pub float_offset: Float,
+
+    /// A duration, in seconds, that it should take for the character to lower its floating height
+    /// when it starts charging.
+    ///
+    /// See
+    /// [`TnuaBuiltinCrouch::height_change_impulse_for_duration`](crate::builtins::TnuaBuiltinCrouch::height_change_impulse_for_duration).
+    pub height_change_impulse_for_duration: Float,
+
+    /// The maximum impulse to apply when lowering the character.
+    pub height_change_impulse_limit: Float,
+
+    /// The parameters of the jump that is done once the charge is released.
+    ///
+    /// The [`height`](TnuaBuiltinJump::height) of this jump is ignored.
+    pub jump: TnuaBuiltinJump,
+}
+
+impl Default for TnuaBuiltinChargeJump {
+    fn default() -> Self {
+        Self {
+            min_height: 1.0,
+            max_height: 4.0,
+            full_charge_duration: 1.0,
+            float_offset: 0.0,
+            height_change_impulse_for_duration: 0.02,
+            height_change_impulse_limit: 40.0,
+            jump: Default::default(),
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinChargeJump {
+    const NAME: &'static str = "TnuaBuiltinChargeJump";
+    type State = TnuaBuiltinChargeJumpState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if !ctx.basis.is_airborne() {
+            TnuaActionInitiationDirective::Allow
+        } else if (being_fed_for.elapsed().as_secs_f64() as Float) < self.jump.input_buffer_time {
+            TnuaActionInitiationDirective::Delay
+        } else {
+            TnuaActionInitiationDirective::Reject
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        if lifecycle_status.just_started() {
+            *state = TnuaBuiltinChargeJumpState::Charging { progress: 0.0 };
+        }
+
+        // TODO: Once `std::mem::variant_count` gets stabilized, use that instead.
+        for _ in 0..3 {
+            return match state {
+                TnuaBuiltinChargeJumpState::NoChargeJump => TnuaActionLifecycleDirective::Finished,
+                TnuaBuiltinChargeJumpState::Charging { progress } => {
+                    match lifecycle_status {
+                        TnuaActionLifecycleStatus::Initiated
+                        | TnuaActionLifecycleStatus::CancelledFrom
+                        | TnuaActionLifecycleStatus::StillFed => {}
+                        TnuaActionLifecycleStatus::CancelledInto => {
+                            return TnuaActionLifecycleDirective::Finished;
+                        }
+                        TnuaActionLifecycleStatus::NoLongerFed => {
+                            *state = TnuaBuiltinChargeJumpState::Jumping {
+                                height: self.min_height
+                                    + (self.max_height - self.min_height) * *progress,
+                                jump_state: Default::default(),
+                            };
+                            continue;
+                        }
+                    }
+                    if ctx.basis.is_airborne() {
+                        return TnuaActionLifecycleDirective::Finished;
+                    }
+                    if !lifecycle_status.just_started() {
+                        *progress = if 0.0 < self.full_charge_duration {
+                            (*progress + ctx.frame_duration / self.full_charge_duration).min(1.0)
+                        } else {
+                            1.0
+                        };
+                    }
+                    if self.float_offset != 0.0 {
+                        self.lower_float_height(&ctx, motor);
+                    }
+                    TnuaActionLifecycleDirective::StillActive
+                }
+                TnuaBuiltinChargeJumpState::Jumping { height, jump_state } => {
+                    let jump = TnuaBuiltinJump {
+                        height: *height,
+                        ..self.jump.clone()
+                    };
+                    let jump_lifecycle_status = match lifecycle_status {
+                        _ if matches!(jump_state, TnuaBuiltinJumpState::NoJump) => {
+                            TnuaActionLifecycleStatus::Initiated
+                        }
+                        TnuaActionLifecycleStatus::CancelledInto => {
+                            TnuaActionLifecycleStatus::CancelledInto
+                        }
+                        // The jump starts after the button was released, so it must not think the
+                        // jump was shortened.
+                        _ => TnuaActionLifecycleStatus::StillFed,
+                    };
+                    jump.apply(jump_state, ctx, jump_lifecycle_status, motor)
+                }
+            };
+        }
+        error!("Tnua could not decide on charge jump state");
+        TnuaActionLifecycleDirective::Finished
+    }
+}
+
+impl TnuaBuiltinChargeJump {
+    fn lower_float_height(&self, ctx: &TnuaActionContext, motor: &mut TnuaMotor) {
+        let Some((walk_basis, walk_state)) = ctx.concrete_basis::<TnuaBuiltinWalk>() else {
+            error!("Cannot lower the float height - basis is not TnuaBuiltinWalk");
+            return;
+        };
+        let Some(sensor_output) = &ctx.proximity_sensor.output else {
+            return;
+        };
+        let up = walk_basis.up.adjust_precision();
+        let spring_offset = walk_basis.float_height - sensor_output.proximity.adjust_precision()
+            + self.float_offset;
+        let spring_force_boost =
+            walk_basis.spring_force_boost(walk_state, &ctx.as_basis_context(), spring_offset);
+        let impulse_boost = if spring_offset < -0.01 {
+            (spring_offset / self.height_change_impulse_for_duration).clamp(
+                -self.height_change_impulse_limit,
+                self.height_change_impulse_limit,
+            )
+        } else {
+            0.0
+        };
+        let boost = if spring_force_boost.abs() < impulse_boost.abs() {
+            impulse_boost
+        } else {
+            spring_force_boost
+        };
+        motor.lin.cancel_on_axis(up);
+        motor.lin += TnuaVelChange::boost(boost * up);
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub enum TnuaBuiltinChargeJumpState {
+    #[default]
+    NoChargeJump,
+    /// The character is charging the jump.
+    Charging {
+        /// How much the jump was charged, from 0.0 when the charge starts to 1.0 when the jump is
+        /// fully charged.
+        progress: Float,
+    },
+    /// The charge was released and the character is jumping.
+    Jumping {
+        /// The height of the jump, decided by the charge.
+        height: Float,
+        /// The state of the underlying jump.
+        jump_state: TnuaBuiltinJumpState,
+    },
+}
+
+impl TnuaBuiltinChargeJumpState {
+    /// How much the jump was charged, from 0.0 when the charge starts to 1.0 when the jump is
+    /// fully charged, or `None` if the jump is not being charged.
+    ///
+    /// Useful for displaying a charge meter in the UI.
+    pub fn charge_progress(&self) -> Option<Float> {
+        if let Self::Charging { progress } = self {
+            Some(*progress)
+        } else {
+            None
+        }
+    }
+}
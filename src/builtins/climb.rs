@@ -0,0 +1,341 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector2, Vector3};
+
+use crate::basis_action_traits::TnuaBasisContext;
+use crate::util::upright_and_turning_boost;
+use crate::{TnuaBasis, TnuaVelChange};
+
+/// A marker for surfaces the character can climb on with [`TnuaBuiltinClimb`] - e.g. ladders,
+/// vines, or climbable walls.
+#[derive(Component, Default, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct TnuaClimbable;
+
+/// A [basis](TnuaBasis) for climbing on a [`TnuaClimbable`] surface.
+///
+/// While climbing this basis cancels gravity, keeps the character at
+/// [`snap_distance`](Self::snap_distance) from the surface, turns it to face the surface, and
+/// moves it along the surface's plane according to the 2D
+/// [`desired_velocity`](Self::desired_velocity).
+///
+/// This basis does not detect the surface by itself. The character needs a proximity sensor
+/// pointed forward - a [`TnuaProximitySensor`](crate::TnuaProximitySensor) on a child entity with
+/// a [`TnuaSubservientSensor`](crate::subservient_sensors::TnuaSubservientSensor) - and the
+/// control system must check that the entity it hits is [`TnuaClimbable`] and feed its normal and
+/// proximity into [`surface_normal`](Self::surface_normal) and
+/// [`surface_distance`](Self::surface_distance). The control system is also responsible for
+/// switching between this basis and the walk basis. It should switch back to walking when
+/// [`exit`](TnuaBuiltinClimbState::exit) returns something, or when the character jumps off the
+/// surface:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_tnua::prelude::*;
+/// # use bevy_tnua::builtins::{TnuaBuiltinClimb, TnuaClimbable};
+/// # use bevy_tnua::math::{AdjustPrecision, Vector2, Vector3};
+/// # use bevy_tnua::TnuaProximitySensor;
+/// # #[derive(Component)]
+/// # struct PlayerInputComponent;
+/// # impl PlayerInputComponent {
+/// # fn direction_vector(&self) -> Vector3 { Vector3::ZERO }
+/// # fn climb_vector(&self) -> Vector2 { Vector2::ZERO }
+/// # fn jump_pressed(&self) -> bool { false }
+/// # }
+/// #[derive(Component)]
+/// struct ClimbSensorEntity(Entity);
+///
+/// fn player_control_system(
+///     mut query: Query<(&mut TnuaController, &ClimbSensorEntity, &PlayerInputComponent)>,
+///     sensors_query: Query<&TnuaProximitySensor>,
+///     climbables_query: Query<(), With<TnuaClimbable>>,
+/// ) {
+///     for (mut controller, climb_sensor_entity, player_input) in query.iter_mut() {
+///         let surface = sensors_query
+///             .get(climb_sensor_entity.0)
+///             .ok()
+///             .and_then(|sensor| sensor.output.as_ref())
+///             .filter(|output| climbables_query.contains(output.entity));
+///         let climbing = if let Some((_, climb_state)) =
+///             controller.concrete_basis::<TnuaBuiltinClimb>()
+///         {
+///             climb_state.exit().is_none()
+///                 && controller.action_name() != Some(TnuaBuiltinJump::NAME)
+///         } else {
+///             surface.is_some() && 0.0 < player_input.climb_vector().y
+///         };
+///         if climbing {
+///             controller.basis(TnuaBuiltinClimb {
+///                 desired_velocity: player_input.climb_vector() * 3.0,
+///                 surface_normal: surface
+///                     .map_or(Vector3::ZERO, |output| output.normal.adjust_precision()),
+///                 surface_distance: surface.map_or(0.0, |output| output.proximity),
+///                 exit_ground_distance: 1.5,
+///                 ..Default::default()
+///             });
+///         } else {
+///             controller.basis(TnuaBuiltinWalk {
+///                 desired_velocity: player_input.direction_vector() * 10.0,
+///                 float_height: 1.5,
+///                 ..Default::default()
+///             });
+///         }
+///         if player_input.jump_pressed() {
+///             controller.action(TnuaBuiltinJump {
+///                 height: 4.0,
+///                 ..Default::default()
+///             });
+///         }
+///     }
+/// }
+/// ```
+///
+/// [`TnuaBuiltinJump`](crate::builtins::TnuaBuiltinJump) can be used while climbing. The jump
+/// will push the character away from the surface at
+/// [`jump_push_speed`](Self::jump_push_speed), so the control system should switch back to
+/// walking once the jump starts - otherwise this basis will pull the character back to the
+/// surface.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinClimb {
+    /// The speed to climb in, along the plane of the surface.
+    ///
+    /// The X component moves the character to its right and the Y component moves it up along the
+    /// surface.
+    pub desired_velocity: Vector2,
+
+    /// The normal of the climbable surface, pointing toward the character.
+    ///
+    /// This should be fed every frame from the forward proximity sensor, or `Vector3::ZERO` if the
+    /// sensor does not detect a climbable surface.
+    pub surface_normal: Vector3,
+
+    /// The distance of the character's center from the climbable surface.
+    ///
+    /// This should be fed every frame from the forward proximity sensor. Since the character is
+    /// turned to face the surface, the sensor's proximity is a good enough approximation.
+    pub surface_distance: Float,
+
+    /// The direction considered as upward.
+    ///
+    /// Typically `Vector3::Y`.
+    pub up: Direction3d,
+
+    /// The distance from the surface to keep the character's center at.
+    pub snap_distance: Float,
+
+    /// The maximum speed for moving the character toward (or away from) the surface in order to
+    /// keep it at the [`snap_distance`](Self::snap_distance).
+    pub snap_speed: Float,
+
+    /// The maximum acceleration for reaching the desired velocity.
+    pub acceleration: Float,
+
+    /// When climbing down and ground is detected closer than this, the character steps off the
+    /// surface and [`exit`](TnuaBuiltinClimbState::exit) will return
+    /// [`BottomStepOff`](TnuaBuiltinClimbExit::BottomStepOff).
+    ///
+    /// This is also used to detect the ground the character lands on when dismounting at the top
+    /// of the surface. It should be a bit more than the [float
+    /// height](crate::builtins::TnuaBuiltinWalk::float_height) of the walk basis.
+    pub exit_ground_distance: Float,
+
+    /// The upward speed for getting over the top of the surface when climbing past its end.
+    pub top_dismount_upward_speed: Float,
+
+    /// The forward speed for getting over the top of the surface when climbing past its end.
+    pub top_dismount_forward_speed: Float,
+
+    /// The maximum duration, in seconds, of the top dismount.
+    ///
+    /// The top dismount ends earlier if it brings the character to ground within
+    /// [`exit_ground_distance`](Self::exit_ground_distance).
+    pub top_dismount_duration: Float,
+
+    /// The speed in which a jump pushes the character away from the surface.
+    pub jump_push_speed: Float,
+
+    /// The maximum angular velocity used for keeping the character upright.
+    pub tilt_offset_angvel: Float,
+
+    /// The maximum angular acceleration used for reaching `tilt_offset_angvel`.
+    pub tilt_offset_angacl: Float,
+
+    /// The maximum angular velocity used for turning the character to face the surface.
+    pub turning_angvel: Float,
+}
+
+impl Default for TnuaBuiltinClimb {
+    fn default() -> Self {
+        Self {
+            desired_velocity: Vector2::ZERO,
+            surface_normal: Vector3::ZERO,
+            surface_distance: 0.0,
+            up: Direction3d::Y,
+            snap_distance: 0.5,
+            snap_speed: 5.0,
+            acceleration: 60.0,
+            exit_ground_distance: 0.0,
+            top_dismount_upward_speed: 4.0,
+            top_dismount_forward_speed: 3.0,
+            top_dismount_duration: 0.5,
+            jump_push_speed: 5.0,
+            tilt_offset_angvel: 5.0,
+            tilt_offset_angacl: 500.0,
+            turning_angvel: 10.0,
+        }
+    }
+}
+
+impl TnuaBasis for TnuaBuiltinClimb {
+    const NAME: &'static str = "TnuaBuiltinClimb";
+    type State = TnuaBuiltinClimbState;
+
+    fn apply(&self, state: &mut Self::State, ctx: TnuaBasisContext, motor: &mut crate::TnuaMotor) {
+        let up = self.up.adjust_precision();
+        state.effective_velocity = ctx.tracker.velocity;
+        let ground_in_reach = ctx
+            .proximity_sensor
+            .output
+            .as_ref()
+            .is_some_and(|output| output.proximity <= self.exit_ground_distance);
+
+        let surface_normal = self.surface_normal.try_normalize();
+        if state.top_dismount_elapsed.is_none()
+            && surface_normal.is_none()
+            && state.surface_normal != Vector3::ZERO
+            && 0.0 < self.desired_velocity.y
+        {
+            // Climbed past the end of the surface
+            state.top_dismount_elapsed = Some(0.0);
+        }
+
+        let (target_velocity, desired_forward) = if let Some(elapsed) =
+            state.top_dismount_elapsed.as_mut()
+        {
+            *elapsed += ctx.frame_duration;
+            if ground_in_reach || self.top_dismount_duration <= *elapsed {
+                state.exit = Some(TnuaBuiltinClimbExit::TopDismount);
+            }
+            let forward = (-state.surface_normal).reject_from(up).normalize_or_zero();
+            (
+                self.top_dismount_upward_speed * up + self.top_dismount_forward_speed * forward,
+                forward,
+            )
+        } else if let Some(normal) = surface_normal {
+            state.surface_normal = normal;
+            state.exit = if self.desired_velocity.y < 0.0 && ground_in_reach {
+                Some(TnuaBuiltinClimbExit::BottomStepOff)
+            } else {
+                None
+            };
+            let surface_up = up.reject_from(normal).try_normalize().unwrap_or(up);
+            let surface_right = (-normal).cross(surface_up);
+            let snap_speed = ((self.snap_distance - self.surface_distance) / ctx.frame_duration)
+                .clamp(-self.snap_speed, self.snap_speed);
+            (
+                self.desired_velocity.x * surface_right
+                    + self.desired_velocity.y * surface_up
+                    + snap_speed * normal,
+                -normal,
+            )
+        } else {
+            state.exit = Some(TnuaBuiltinClimbExit::LostSurface);
+            motor.lin = TnuaVelChange::ZERO;
+            motor.ang = TnuaVelChange::ZERO;
+            return;
+        };
+
+        motor.lin = TnuaVelChange {
+            acceleration: -ctx.tracker.gravity,
+            boost: (target_velocity - ctx.tracker.velocity)
+                .clamp_length_max(self.acceleration * ctx.frame_duration),
+        };
+
+        motor.ang = TnuaVelChange::boost(upright_and_turning_boost(
+            ctx.tracker,
+            ctx.frame_duration,
+            self.up,
+            desired_forward,
+            self.tilt_offset_angvel,
+            self.tilt_offset_angacl,
+            self.turning_angvel,
+        ));
+    }
+
+    fn proximity_sensor_cast_range(&self, _state: &Self::State) -> Float {
+        self.exit_ground_distance
+    }
+
+    fn up_direction(&self, _state: &Self::State) -> Direction3d {
+        self.up
+    }
+
+    fn displacement(&self, _state: &Self::State) -> Option<Vector3> {
+        None
+    }
+
+    fn effective_velocity(&self, state: &Self::State) -> Vector3 {
+        state.effective_velocity
+    }
+
+    fn vertical_velocity(&self, _state: &Self::State) -> Float {
+        0.0
+    }
+
+    fn neutralize(&mut self) {
+        self.desired_velocity = Vector2::ZERO;
+    }
+
+    fn is_airborne(&self, state: &Self::State) -> bool {
+        !state.is_holding_surface()
+    }
+
+    fn jump_push_velocity(&self, state: &Self::State) -> Vector3 {
+        if state.is_holding_surface() {
+            self.jump_push_speed * state.surface_normal.reject_from(self.up.adjust_precision())
+        } else {
+            Vector3::ZERO
+        }
+    }
+
+    fn violate_coyote_time(&self, _state: &mut Self::State) {}
+}
+
+/// The reason the character stopped climbing. See [`TnuaBuiltinClimbState::exit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TnuaBuiltinClimbExit {
+    /// The character climbed past the top of the surface and got over it.
+    TopDismount,
+    /// The character climbed down to the ground.
+    BottomStepOff,
+    /// The surface is no longer detected - e.g. because the character climbed past its side.
+    LostSurface,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinClimbState {
+    effective_velocity: Vector3,
+    surface_normal: Vector3,
+    top_dismount_elapsed: Option<Float>,
+    exit: Option<TnuaBuiltinClimbExit>,
+}
+
+impl TnuaBuiltinClimbState {
+    /// Check if the character should stop climbing, and why.
+    ///
+    /// When this returns something, the control system should switch back to the walk basis.
+    pub fn exit(&self) -> Option<TnuaBuiltinClimbExit> {
+        self.exit
+    }
+
+    /// Check if the character is in the middle of getting over the top of the surface.
+    pub fn is_dismounting(&self) -> bool {
+        self.top_dismount_elapsed.is_some()
+    }
+
+    fn is_holding_surface(&self) -> bool {
+        self.top_dismount_elapsed.is_none() && self.exit != Some(TnuaBuiltinClimbExit::LostSurface)
+    }
+}
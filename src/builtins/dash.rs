@@ -8,7 +8,10 @@ use crate::{
 };
 
 /// The basic dash [action](TnuaAction).
-#[derive(Clone)]
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct TnuaBuiltinDash {
     /// The direction and distance of the dash.
     ///
@@ -172,7 +175,7 @@ impl TnuaAction for TnuaBuiltinDash {
     }
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub enum TnuaBuiltinDashState {
     #[default]
     PreDash,
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+use super::TnuaBuiltinJumpState;
+
+/// A jump [action](TnuaAction) that also launches the character horizontally - for long jumps,
+/// backflips, side flips and the like.
+///
+/// The vertical part of the jump is done by [`jump`](Self::jump), and its
+/// [`height`](TnuaBuiltinJump::height) is the height of the top of the arc. The horizontal
+/// velocity is set when the jump starts, so that the character will travel
+/// [`distance`](Self::distance) in [`direction`](Self::direction) by the time it lands back at the
+/// height it jumped from. During the jump the basis' air control is reduced to
+/// [`air_control`](Self::air_control) of its usual strength, so that the arc will be predictable.
+///
+/// Unlike [`TnuaBuiltinJump`], releasing the jump button does not shorten the jump - once it starts
+/// it'll continue until the character lands, even if the action is no longer fed.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinDirectionalJump {
+    /// The horizontal direction to launch the character in.
+    ///
+    /// This input parameter is cached when the action starts.
+    pub direction: Vector3,
+
+    /// The horizontal distance the character should travel before landing back at the height it
+    /// jumped from.
+    ///
+    /// This input parameter is cached when the action starts.
+    pub distance: Float,
+
+    /// The fraction of the basis' horizontal air control that still applies during the jump.
+    ///
+    /// Set to 0.0 to make the arc completely predictable.
+    pub air_control: Float,
+
+    /// The parameters of the vertical part of the jump.
+    ///
+    /// The [`height`](TnuaBuiltinJump::height) of this jump is the height of the top of the arc.
+    pub jump: TnuaBuiltinJump,
+}
+
+impl Default for TnuaBuiltinDirectionalJump {
+    fn default() -> Self {
+        Self {
+            direction: Vector3::ZERO,
+            distance: 0.0,
+            air_control: 0.0,
+            jump: Default::default(),
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinDirectionalJump {
+    const NAME: &'static str = "TnuaBuiltinDirectionalJump";
+    type State = TnuaBuiltinDirectionalJumpState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        self.jump.initiation_decision(ctx, being_fed_for)
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        let up = ctx.basis.up_direction().adjust_precision();
+
+        if lifecycle_status.just_started() {
+            let gravity = ctx.tracker.gravity.dot(-up);
+            let height = self.jump.effective_height(&ctx);
+            let rise_duration = self
+                .jump
+                .initial_velocity_calculator(height, gravity)
+                .duration();
+            let fall_duration = (2.0 * height / (gravity + self.jump.fall_extra_gravity)).sqrt();
+            let flight_duration = rise_duration + fall_duration;
+            *state = TnuaBuiltinDirectionalJumpState {
+                launch_velocity: if 0.0 < flight_duration {
+                    self.direction.reject_from(up).normalize_or_zero() * self.distance
+                        / flight_duration
+                } else {
+                    Vector3::ZERO
+                },
+                jump_state: Default::default(),
+            };
+        }
+
+        motor.lin.boost =
+            motor.lin.boost.project_onto(up) + self.air_control * motor.lin.boost.reject_from(up);
+        motor.lin.acceleration = motor.lin.acceleration.project_onto(up)
+            + self.air_control * motor.lin.acceleration.reject_from(up);
+
+        let jump_lifecycle_status = match lifecycle_status {
+            TnuaActionLifecycleStatus::Initiated | TnuaActionLifecycleStatus::CancelledFrom => {
+                lifecycle_status
+            }
+            TnuaActionLifecycleStatus::CancelledInto => TnuaActionLifecycleStatus::CancelledInto,
+            // The jump is not shortened when the button is released.
+            TnuaActionLifecycleStatus::StillFed | TnuaActionLifecycleStatus::NoLongerFed => {
+                TnuaActionLifecycleStatus::StillFed
+            }
+        };
+        let directive = self
+            .jump
+            .apply(&mut state.jump_state, ctx, jump_lifecycle_status, motor);
+
+        // Keep setting the launch velocity until the character actually takes off.
+        if matches!(state.jump_state, TnuaBuiltinJumpState::StartingJump { .. })
+            || lifecycle_status.just_started()
+        {
+            let planar_velocity = ctx.basis.effective_velocity().reject_from(up);
+            motor.lin.boost =
+                motor.lin.boost.project_onto(up) + state.launch_velocity - planar_velocity;
+            motor.lin.acceleration = motor.lin.acceleration.project_onto(up);
+        }
+
+        directive
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinDirectionalJumpState {
+    launch_velocity: Vector3,
+    jump_state: TnuaBuiltinJumpState,
+}
+
+impl TnuaBuiltinDirectionalJumpState {
+    /// The horizontal velocity the character was launched in.
+    pub fn launch_velocity(&self) -> Vector3 {
+        self.launch_velocity
+    }
+
+    /// The state of the vertical part of the jump.
+    pub fn jump_state(&self) -> &TnuaBuiltinJumpState {
+        &self.jump_state
+    }
+}
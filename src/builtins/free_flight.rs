@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AsF32, Float, Quaternion, Vector3};
+
+use crate::basis_action_traits::TnuaBasisContext;
+use crate::{TnuaBasis, TnuaVelChange};
+
+/// A [basis](TnuaBasis) for flying freely in all directions, ignoring the ground.
+///
+/// This basis can be used for jetpacks, spaceships, or noclip-style spectator characters. It
+/// accelerates the character toward the [`desired_velocity`](Self::desired_velocity) in all
+/// three dimensions and, unlike [`TnuaBuiltinWalk`](crate::builtins::TnuaBuiltinWalk), it can
+/// control the full 3D orientation of the character - including its up direction.
+///
+/// The character is always considered airborne while using this basis.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinFreeFlight {
+    /// The direction (in the world space) and speed to accelerate to.
+    pub desired_velocity: Vector3,
+
+    /// If non-zero, Tnua will rotate the character so that its negative Z will face in that
+    /// direction.
+    ///
+    /// If this is not orthogonal to [`desired_up`](Self::desired_up), only its projection on the
+    /// plane orthogonal to `desired_up` is used.
+    pub desired_forward: Vector3,
+
+    /// If non-zero, Tnua will rotate the character so that its positive Y will face in that
+    /// direction.
+    ///
+    /// If zero, the character will retain its current up direction.
+    pub desired_up: Vector3,
+
+    /// Cancel the gravity, so that the character will stay in place when the desired velocity is
+    /// zero.
+    pub compensate_gravity: bool,
+
+    /// The maximum acceleration for reaching the desired velocity.
+    pub acceleration: Float,
+
+    /// The maximum angular velocity used for rotating the character to the desired orientation.
+    pub rotation_angvel: Float,
+
+    /// The maximum angular acceleration used for reaching `rotation_angvel`.
+    pub rotation_angacl: Float,
+}
+
+impl Default for TnuaBuiltinFreeFlight {
+    fn default() -> Self {
+        Self {
+            desired_velocity: Vector3::ZERO,
+            desired_forward: Vector3::ZERO,
+            desired_up: Vector3::Y,
+            compensate_gravity: true,
+            acceleration: 60.0,
+            rotation_angvel: 10.0,
+            rotation_angacl: 500.0,
+        }
+    }
+}
+
+impl TnuaBasis for TnuaBuiltinFreeFlight {
+    const NAME: &'static str = "TnuaBuiltinFreeFlight";
+    type State = TnuaBuiltinFreeFlightState;
+
+    fn apply(&self, state: &mut Self::State, ctx: TnuaBasisContext, motor: &mut crate::TnuaMotor) {
+        state.effective_velocity = ctx.tracker.velocity;
+
+        let desired_boost = self.desired_velocity - ctx.tracker.velocity;
+        let flight_vel_change = if self.desired_velocity == Vector3::ZERO {
+            // When stopping, prefer a boost to be able to reach a precise stop
+            TnuaVelChange::boost(
+                desired_boost.clamp_length_max(ctx.frame_duration * self.acceleration),
+            )
+        } else {
+            TnuaVelChange::acceleration(
+                (desired_boost / ctx.frame_duration).clamp_length_max(self.acceleration),
+            )
+        };
+        let gravity_compensation = if self.compensate_gravity {
+            TnuaVelChange::acceleration(-ctx.tracker.gravity)
+        } else {
+            TnuaVelChange::ZERO
+        };
+        motor.lin = flight_vel_change + gravity_compensation;
+
+        // Orientation
+
+        let current_up = ctx.tracker.rotation.mul_vec3(Vector3::Y);
+        let target_up = self.desired_up.try_normalize().unwrap_or(current_up);
+        state.up = target_up;
+
+        let current_forward = ctx.tracker.rotation.mul_vec3(Vector3::NEG_Z);
+        let target_forward = if self.desired_forward == Vector3::ZERO {
+            current_forward
+        } else {
+            self.desired_forward
+        }
+        .reject_from(target_up)
+        .try_normalize();
+
+        let up_rotation = Quaternion::from_rotation_arc(Vector3::Y, target_up);
+        let target_rotation = if let Some(target_forward) = target_forward {
+            let forward_after_up_rotation = up_rotation.mul_vec3(Vector3::NEG_Z);
+            let angle = forward_after_up_rotation
+                .cross(target_forward)
+                .dot(target_up)
+                .atan2(forward_after_up_rotation.dot(target_forward));
+            Quaternion::from_axis_angle(target_up, angle) * up_rotation
+        } else {
+            // The current forward is parallel to the target up, so there is no forward to keep.
+            Quaternion::from_rotation_arc(current_up, target_up) * ctx.tracker.rotation
+        };
+
+        let mut rotation_required = target_rotation * ctx.tracker.rotation.inverse();
+        if rotation_required.w < 0.0 {
+            // Take the short way around
+            rotation_required = -rotation_required;
+        }
+        let desired_angvel = (rotation_required.to_scaled_axis() / ctx.frame_duration)
+            .clamp_length_max(self.rotation_angvel);
+        let angular_velocity_diff = desired_angvel - ctx.tracker.angvel;
+        motor.ang = TnuaVelChange::boost(
+            angular_velocity_diff.clamp_length_max(ctx.frame_duration * self.rotation_angacl),
+        );
+    }
+
+    fn proximity_sensor_cast_range(&self, _state: &Self::State) -> Float {
+        0.0
+    }
+
+    fn up_direction(&self, state: &Self::State) -> Direction3d {
+        Direction3d::new(state.up.f32()).unwrap_or(Direction3d::Y)
+    }
+
+    fn displacement(&self, _state: &Self::State) -> Option<Vector3> {
+        None
+    }
+
+    fn effective_velocity(&self, state: &Self::State) -> Vector3 {
+        state.effective_velocity
+    }
+
+    fn vertical_velocity(&self, _state: &Self::State) -> Float {
+        0.0
+    }
+
+    fn neutralize(&mut self) {
+        self.desired_velocity = Vector3::ZERO;
+        self.desired_forward = Vector3::ZERO;
+    }
+
+    fn is_airborne(&self, _state: &Self::State) -> bool {
+        true
+    }
+
+    fn violate_coyote_time(&self, _state: &mut Self::State) {}
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinFreeFlightState {
+    effective_velocity: Vector3,
+    up: Vector3,
+}
@@ -0,0 +1,149 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+/// An [action](TnuaAction) for gliding (or descending with a parachute) while in the air.
+///
+/// While gliding, the character's fall speed is capped at
+/// [`terminal_fall_speed`](Self::terminal_fall_speed), and some of the fall speed that gets
+/// cancelled is converted into forward speed (see
+/// [`fall_to_forward_ratio`](Self::fall_to_forward_ratio)). The walk basis' air movement is
+/// replaced with slower steering toward [`desired_velocity`](Self::desired_velocity).
+///
+/// The glide can only start while the character is airborne, and it ends when the character
+/// lands or when the action is no longer fed.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinGlide {
+    /// The direction and speed to steer the character in while gliding.
+    ///
+    /// This is usually the same as [`TnuaBuiltinWalk::desired_velocity`](crate::builtins::TnuaBuiltinWalk::desired_velocity).
+    pub desired_velocity: Vector3,
+
+    /// The maximum downward speed while gliding.
+    pub terminal_fall_speed: Float,
+
+    /// The maximum deceleration for slowing the fall down to
+    /// [`terminal_fall_speed`](Self::terminal_fall_speed).
+    ///
+    /// This must be larger than the gravity, or the character will fall faster than the terminal
+    /// fall speed.
+    pub fall_deceleration: Float,
+
+    /// How much of the fall speed that gets cancelled is converted into forward speed.
+    ///
+    /// Set to 0.0 for a parachute that does not move the character forward by itself.
+    pub fall_to_forward_ratio: Float,
+
+    /// The maximum forward speed that can be gained from converting the fall speed.
+    pub max_forward_speed: Float,
+
+    /// The acceleration for steering the character while gliding.
+    ///
+    /// Steering can turn the character and accelerate it, but not slow it down.
+    pub steering_acceleration: Float,
+}
+
+impl Default for TnuaBuiltinGlide {
+    fn default() -> Self {
+        Self {
+            desired_velocity: Vector3::ZERO,
+            terminal_fall_speed: 2.0,
+            fall_deceleration: 30.0,
+            fall_to_forward_ratio: 0.3,
+            max_forward_speed: 8.0,
+            steering_acceleration: 5.0,
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinGlide {
+    const NAME: &'static str = "TnuaBuiltinGlide";
+    type State = TnuaBuiltinGlideState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if ctx.basis.is_airborne() {
+            TnuaActionInitiationDirective::Allow
+        } else {
+            TnuaActionInitiationDirective::Delay
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        if !lifecycle_status.is_active() || !ctx.basis.is_airborne() {
+            return TnuaActionLifecycleDirective::Finished;
+        }
+
+        let up = ctx.basis.up_direction().adjust_precision();
+        let velocity = ctx.basis.effective_velocity();
+        let planar_velocity = velocity.reject_from(up);
+
+        if lifecycle_status.just_started() {
+            state.heading = planar_velocity.normalize_or_zero();
+        }
+        if let Some(heading) = self.desired_velocity.reject_from(up).try_normalize() {
+            state.heading = heading;
+        }
+        let heading = state.heading;
+
+        // Replace the air movement (and the extra fall gravity) of the walk basis with the glide's
+        // own.
+        motor.lin.boost = Vector3::ZERO;
+        motor.lin.acceleration = Vector3::ZERO;
+
+        let fall_speed_next_frame =
+            -velocity.dot(up) - ctx.frame_duration * ctx.tracker.gravity.dot(up);
+        let fall_braking = (fall_speed_next_frame - self.terminal_fall_speed)
+            .clamp(0.0, self.fall_deceleration * ctx.frame_duration);
+        motor.lin.boost += fall_braking * up;
+
+        let forward_speed = planar_velocity.dot(heading);
+        let max_steering_boost = self.steering_acceleration * ctx.frame_duration;
+        let turn_boost =
+            (forward_speed * heading - planar_velocity).clamp_length_max(max_steering_boost);
+        let steer_forward_boost =
+            (self.desired_velocity.length() - forward_speed).clamp(0.0, max_steering_boost);
+        let conversion_boost = (self.fall_to_forward_ratio * fall_braking)
+            .min((self.max_forward_speed - forward_speed).max(0.0));
+        motor.lin.boost += turn_boost + (steer_forward_boost + conversion_boost) * heading;
+
+        state.fall_speed = -velocity.dot(up);
+
+        TnuaActionLifecycleDirective::StillActive
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinGlideState {
+    heading: Vector3,
+    fall_speed: Float,
+}
+
+impl TnuaBuiltinGlideState {
+    /// The direction, orthogonal to the up direction, the character glides toward.
+    pub fn heading(&self) -> Vector3 {
+        self.heading
+    }
+
+    /// The downward speed of the character.
+    pub fn fall_speed(&self) -> Float {
+        self.fall_speed
+    }
+}
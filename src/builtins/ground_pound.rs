@@ -0,0 +1,219 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+/// An [action](TnuaAction) for slamming down into the ground from the air (also known as a dive
+/// or a stomp).
+///
+/// The ground pound starts with a short windup, where the character's horizontal movement stops
+/// (and, if [`windup_hang`](Self::windup_hang) is set, its vertical movement too), and then the
+/// character slams downward at [`slam_speed`](Self::slam_speed) until it lands. Once the
+/// action starts it'll continue until the character lands, even if the action is no longer fed.
+///
+/// When the character lands, the action's state becomes
+/// [`Landed`](TnuaBuiltinGroundPoundState::Landed) with the entity that was hit and the speed of
+/// the impact, and stays that way for [`recovery_duration`](Self::recovery_duration) seconds
+/// (and at least one frame) so that the game can react to the impact:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_tnua::prelude::*;
+/// # use bevy_tnua::builtins::TnuaBuiltinGroundPound;
+/// # #[derive(Component)]
+/// # struct Crate;
+/// fn break_crates_system(
+///     query: Query<&TnuaController>,
+///     crates_query: Query<(), With<Crate>>,
+///     mut commands: Commands,
+/// ) {
+///     for controller in query.iter() {
+///         let Some((_, state)) = controller.concrete_action::<TnuaBuiltinGroundPound>() else {
+///             continue;
+///         };
+///         if let Some((entity, _impact_speed)) = state.impact() {
+///             if state.just_landed() && crates_query.contains(entity) {
+///                 commands.entity(entity).despawn_recursive();
+///             }
+///         }
+///     }
+/// }
+/// ```
+///
+/// This action violates the coyote time, so it is counted as an air action by
+/// [`TnuaSimpleAirActionsCounter`](crate::control_helpers::TnuaSimpleAirActionsCounter) - just
+/// like air jumps and air dashes - and the counter gets reset once the character lands from it.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinGroundPound {
+    /// The duration, in seconds, of the windup before the slam. During the windup the
+    /// character's horizontal movement stops.
+    pub windup_duration: Float,
+
+    /// Make the character hang in the air during the windup, instead of letting it keep rising or
+    /// falling.
+    pub windup_hang: bool,
+
+    /// The downward speed of the slam.
+    pub slam_speed: Float,
+
+    /// The duration, in seconds, the character stays in place after landing before the action
+    /// finishes.
+    pub recovery_duration: Float,
+}
+
+impl Default for TnuaBuiltinGroundPound {
+    fn default() -> Self {
+        Self {
+            windup_duration: 0.2,
+            windup_hang: false,
+            slam_speed: 25.0,
+            recovery_duration: 0.2,
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinGroundPound {
+    const NAME: &'static str = "TnuaBuiltinGroundPound";
+    type State = TnuaBuiltinGroundPoundState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if ctx.basis.is_airborne() {
+            TnuaActionInitiationDirective::Allow
+        } else {
+            TnuaActionInitiationDirective::Reject
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        if matches!(lifecycle_status, TnuaActionLifecycleStatus::CancelledInto) {
+            return TnuaActionLifecycleDirective::Finished;
+        }
+
+        let up = ctx.basis.up_direction().adjust_precision();
+        let velocity = ctx.basis.effective_velocity();
+
+        if lifecycle_status.just_started() {
+            *state = TnuaBuiltinGroundPoundState::Windup { elapsed: 0.0 };
+        } else if let TnuaBuiltinGroundPoundState::Landed {
+            time_since_impact, ..
+        } = state
+        {
+            *time_since_impact += ctx.frame_duration;
+        }
+
+        // TODO: Once `std::mem::variant_count` gets stabilized, use that instead.
+        for _ in 0..4 {
+            return match state {
+                TnuaBuiltinGroundPoundState::NoGroundPound => {
+                    TnuaActionLifecycleDirective::Finished
+                }
+                TnuaBuiltinGroundPoundState::Windup { elapsed } => {
+                    if self.windup_duration <= *elapsed {
+                        *state = TnuaBuiltinGroundPoundState::Slamming;
+                        continue;
+                    }
+                    *elapsed += ctx.frame_duration;
+                    // Freeze the horizontal movement, and leave the vertical movement to the basis.
+                    motor.lin.boost = motor.lin.boost.project_onto(up) - velocity.reject_from(up);
+                    motor.lin.acceleration = motor.lin.acceleration.project_onto(up);
+                    if self.windup_hang {
+                        motor.lin.cancel_on_axis(up);
+                        motor.lin.acceleration -= ctx.tracker.gravity.project_onto(up);
+                        motor.lin.boost -= velocity.project_onto(up);
+                    }
+                    TnuaActionLifecycleDirective::StillActive
+                }
+                TnuaBuiltinGroundPoundState::Slamming => {
+                    if !ctx.basis.is_airborne() {
+                        let Some(sensor_output) = ctx.proximity_sensor.output.as_ref() else {
+                            return TnuaActionLifecycleDirective::Finished;
+                        };
+                        *state = TnuaBuiltinGroundPoundState::Landed {
+                            entity: sensor_output.entity,
+                            impact_speed: -velocity.dot(up),
+                            time_since_impact: 0.0,
+                        };
+                        continue;
+                    }
+                    motor.lin.acceleration = -ctx.tracker.gravity;
+                    motor.lin.boost = -self.slam_speed * up - velocity;
+                    TnuaActionLifecycleDirective::StillActive
+                }
+                TnuaBuiltinGroundPoundState::Landed {
+                    time_since_impact, ..
+                } => {
+                    if self.recovery_duration < *time_since_impact {
+                        return TnuaActionLifecycleDirective::Finished;
+                    }
+                    // Keep the floating from the walk basis, but stop the horizontal movement.
+                    motor.lin.boost = motor.lin.boost.project_onto(up) - velocity.reject_from(up);
+                    motor.lin.acceleration = motor.lin.acceleration.project_onto(up);
+                    TnuaActionLifecycleDirective::StillActive
+                }
+            };
+        }
+        error!("Tnua could not decide on ground pound state");
+        TnuaActionLifecycleDirective::Finished
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub enum TnuaBuiltinGroundPoundState {
+    #[default]
+    NoGroundPound,
+    /// The character's horizontal movement stops before the slam.
+    Windup {
+        /// The time, in seconds, since the windup started.
+        elapsed: Float,
+    },
+    /// The character slams downward.
+    Slamming,
+    /// The character has landed, and is recovering from the ground pound.
+    Landed {
+        /// The entity the character has landed on.
+        entity: Entity,
+        /// The downward speed, relative to the ground, in which the character hit it.
+        impact_speed: Float,
+        /// The time, in seconds, since the character has landed.
+        time_since_impact: Float,
+    },
+}
+
+impl TnuaBuiltinGroundPoundState {
+    /// The entity the character has landed on and the speed of the impact, if the character has
+    /// already landed.
+    pub fn impact(&self) -> Option<(Entity, Float)> {
+        if let Self::Landed {
+            entity,
+            impact_speed,
+            ..
+        } = self
+        {
+            Some((*entity, *impact_speed))
+        } else {
+            None
+        }
+    }
+
+    /// Check if the character has landed in the current frame.
+    pub fn just_landed(&self) -> bool {
+        matches!(self, Self::Landed { time_since_impact, .. } if *time_since_impact == 0.0)
+    }
+}
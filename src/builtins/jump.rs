@@ -17,7 +17,10 @@ use crate::util::SegmentedJumpInitialVelocityCalculator;
 /// being fed, it'll apply extra gravity to shorten the jump. If the game desires fixed height
 /// jumps instead (where the player cannot make lower jumps by tapping the jump button)
 /// [`shorten_extra_gravity`](Self::shorten_extra_gravity) should be set to `0.0`.
-#[derive(Clone)]
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct TnuaBuiltinJump {
     /// The height the character will jump to.
     ///
@@ -149,15 +152,11 @@ impl TnuaAction for TnuaBuiltinJump {
         let up = ctx.basis.up_direction().adjust_precision();
 
         if lifecycle_status.just_started() {
-            let mut calculator = SegmentedJumpInitialVelocityCalculator::new(self.height);
-            let gravity = ctx.tracker.gravity.dot(-up);
-            let kinetic_energy = calculator
-                .add_segment(
-                    gravity + self.peak_prevention_extra_gravity,
-                    self.peak_prevention_at_upward_velocity,
+            let kinetic_energy = self
+                .initial_velocity_calculator(
+                    self.effective_height(&ctx),
+                    ctx.tracker.gravity.dot(-up),
                 )
-                .add_segment(gravity, self.takeoff_above_velocity)
-                .add_segment(gravity + self.takeoff_extra_gravity, Float::INFINITY)
                 .kinetic_energy();
             *state = TnuaBuiltinJumpState::StartingJump {
                 desired_energy: kinetic_energy,
@@ -188,6 +187,11 @@ impl TnuaAction for TnuaBuiltinJump {
                     let relative_velocity =
                         effective_velocity.dot(up) - ctx.basis.vertical_velocity().max(0.0);
 
+                    Self::apply_push_velocity(
+                        motor,
+                        effective_velocity,
+                        ctx.basis.jump_push_velocity(),
+                    );
                     motor.lin.cancel_on_axis(up);
                     motor.lin.boost += (desired_upward_velocity - relative_velocity) * up;
                     if 0.0 <= extra_height {
@@ -308,6 +312,45 @@ impl TnuaAction for TnuaBuiltinJump {
 }
 
 impl TnuaBuiltinJump {
+    /// The [`height`](Self::height) of the jump, modified by the
+    /// [`TnuaSurface`](crate::TnuaSurface) the character jumps from.
+    pub(crate) fn effective_height(&self, ctx: &TnuaActionContext) -> Float {
+        self.height
+            * ctx
+                .surface
+                .map_or(1.0, |surface| surface.jump_height_multiplier)
+    }
+
+    pub(crate) fn initial_velocity_calculator(
+        &self,
+        height: Float,
+        gravity: Float,
+    ) -> SegmentedJumpInitialVelocityCalculator {
+        let mut calculator = SegmentedJumpInitialVelocityCalculator::new(height);
+        calculator
+            .add_segment(
+                gravity + self.peak_prevention_extra_gravity,
+                self.peak_prevention_at_upward_velocity,
+            )
+            .add_segment(gravity, self.takeoff_above_velocity)
+            .add_segment(gravity + self.takeoff_extra_gravity, Float::INFINITY);
+        calculator
+    }
+
+    /// Boost the character so that its velocity in the direction of `push_velocity` will be at
+    /// least as fast as `push_velocity`.
+    pub(crate) fn apply_push_velocity(
+        motor: &mut crate::TnuaMotor,
+        effective_velocity: Vector3,
+        push_velocity: Vector3,
+    ) {
+        if let Some(push_direction) = push_velocity.try_normalize() {
+            let current_push = effective_velocity.dot(push_direction);
+            motor.lin.cancel_on_axis(push_direction);
+            motor.lin.boost += (push_velocity.length() - current_push).max(0.0) * push_direction;
+        }
+    }
+
     fn finish_or_reschedule(&self) -> TnuaActionLifecycleDirective {
         if let Some(cooldown) = self.reschedule_cooldown {
             TnuaActionLifecycleDirective::Reschedule {
@@ -330,7 +373,7 @@ impl TnuaBuiltinJump {
     }
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub enum TnuaBuiltinJumpState {
     #[default]
     NoJump,
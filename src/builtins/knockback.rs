@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+/// An [action](TnuaAction) for throwing the character back when it gets hit.
+///
+/// When the action starts, [`impulse`](Self::impulse) is added to the character's velocity. Then,
+/// for [`stun_duration`](Self::stun_duration) seconds, the horizontal movement of the basis is
+/// reduced to [`control_fraction`](Self::control_fraction) of its usual strength, so that the
+/// basis will not immediately fight the knockback back toward its desired velocity. While the
+/// character moves upward during the stun, the basis' vertical movement is suppressed as well so
+/// that the knockback can throw the character off the ground.
+///
+/// The game should feed this action in the frame the character got hit. Once it starts it'll
+/// continue until the stun is over, even if the action is no longer fed. The
+/// [`impulse`](Self::impulse) is only applied when the action starts - continuing to feed the
+/// action while the character is stunned will not apply it again. If the action stops being fed
+/// and is then fed again (e.g. because the character got hit again), it is treated as a new
+/// knockback that replaces the current one - or waits for the stun to be over, if the current one
+/// is [`uncancellable`](Self::uncancellable).
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinKnockback {
+    /// The velocity change to apply to the character when the knockback starts.
+    pub impulse: Vector3,
+
+    /// The duration, in seconds, the character is stunned after the knockback.
+    ///
+    /// Set to 0.0 to only apply the [`impulse`](Self::impulse), without stunning the character.
+    pub stun_duration: Float,
+
+    /// The fraction of the basis' horizontal movement that still applies while the character is
+    /// stunned.
+    ///
+    /// Set to 0.0 to completely disable the movement controls (and the braking) during the stun.
+    pub control_fraction: Float,
+
+    /// If set to `true`, other actions cannot cancel the knockback until the stun is over.
+    ///
+    /// Actions that are fed during the stun are not discarded - they wait, and start once the
+    /// stun is over, as long as they are still being fed and their
+    /// [`initiation_decision`](TnuaAction::initiation_decision) does not reject them in the
+    /// meantime. For example - if the player holds the jump button while stunned, the character
+    /// will jump right away if `uncancellable` is `false`. But if `uncancellable` is `true`, the
+    /// character will only jump when the stun is over - and only if it can still jump then (e.g.
+    /// it has not been knocked off the ground, or the jump's
+    /// [`input_buffer_time`](crate::builtins::TnuaBuiltinJump::input_buffer_time) has not passed
+    /// yet).
+    pub uncancellable: bool,
+}
+
+impl Default for TnuaBuiltinKnockback {
+    fn default() -> Self {
+        Self {
+            impulse: Vector3::ZERO,
+            stun_duration: 0.5,
+            control_fraction: 0.0,
+            uncancellable: false,
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinKnockback {
+    const NAME: &'static str = "TnuaBuiltinKnockback";
+    type State = TnuaBuiltinKnockbackState;
+    const VIOLATES_COYOTE_TIME: bool = false;
+
+    fn initiation_decision(
+        &self,
+        _ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if self.impulse.is_finite() {
+            TnuaActionInitiationDirective::Allow
+        } else {
+            TnuaActionInitiationDirective::Reject
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        if lifecycle_status.just_started() {
+            *state = Default::default();
+        } else {
+            state.elapsed += ctx.frame_duration;
+        }
+
+        if matches!(lifecycle_status, TnuaActionLifecycleStatus::CancelledInto)
+            && !self.uncancellable
+        {
+            return TnuaActionLifecycleDirective::Finished;
+        }
+        // Even without a stun, the impulse must be applied in the first frame.
+        if !lifecycle_status.just_started() && self.stun_duration <= state.elapsed {
+            return TnuaActionLifecycleDirective::Finished;
+        }
+
+        let up = ctx.basis.up_direction().adjust_precision();
+        let mut upward_velocity = ctx.basis.effective_velocity().dot(up);
+
+        motor.lin.acceleration = motor.lin.acceleration.project_onto(up)
+            + self.control_fraction * motor.lin.acceleration.reject_from(up);
+        motor.lin.boost = motor.lin.boost.project_onto(up)
+            + self.control_fraction * motor.lin.boost.reject_from(up);
+        if lifecycle_status.just_started() {
+            upward_velocity += self.impulse.dot(up);
+        }
+        if 0.0 < upward_velocity {
+            motor.lin.cancel_on_axis(up);
+        }
+        if lifecycle_status.just_started() {
+            motor.lin.boost += self.impulse;
+        }
+
+        TnuaActionLifecycleDirective::StillActive
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinKnockbackState {
+    elapsed: Float,
+}
+
+impl TnuaBuiltinKnockbackState {
+    /// The time, in seconds, since the knockback started.
+    pub fn elapsed(&self) -> Float {
+        self.elapsed
+    }
+}
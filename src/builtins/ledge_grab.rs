@@ -0,0 +1,209 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+/// An [action](TnuaAction) for grabbing a ledge and hanging from it.
+///
+/// This action does not detect the ledge by itself. The character entity needs a
+/// [`TnuaLedgeSensor`](crate::TnuaLedgeSensor), and the control system must feed its output into
+/// [`ledge_point`](Self::ledge_point) and [`ledge_normal`](Self::ledge_normal), and the sensor's
+/// cast direction into [`ledge_direction`](Self::ledge_direction). The sensor's output must keep
+/// being fed while hanging - the character lets go of the ledge once the sensor no longer detects
+/// it (e.g. when it shimmies past the ledge's end). For that, the sensor's cast origin should be
+/// a bit higher above the character's center than [`hang_offset`](Self::hang_offset), so that
+/// its forward cast passes over the ledge while the character hangs from it.
+///
+/// While hanging the character does not move, except for shimmying along the ledge with
+/// [`desired_shimmy`](Self::desired_shimmy) (the ledge is assumed to be straight). Setting
+/// [`climb_up`](Self::climb_up) pulls the character up and onto the top of the ledge, and no
+/// longer feeding the action drops it.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinLedgeGrab {
+    /// The point (in world space) on the top of the ledge, as detected by the
+    /// [`TnuaLedgeSensor`](crate::TnuaLedgeSensor).
+    pub ledge_point: Vector3,
+
+    /// The normal of the top of the ledge, or `Vector3::ZERO` if the sensor did not detect a
+    /// ledge.
+    ///
+    /// Setting this to `Vector3::ZERO` while the character hangs from the ledge makes it drop.
+    pub ledge_normal: Vector3,
+
+    /// The direction from the character toward the ledge - usually the cast direction of the
+    /// [`TnuaLedgeSensor`](crate::TnuaLedgeSensor).
+    pub ledge_direction: Vector3,
+
+    /// The speed to shimmy along the ledge while hanging from it. Positive values move the
+    /// character to its right (when facing the ledge) and negative values move it to its left.
+    pub desired_shimmy: Float,
+
+    /// Climb up from the ledge onto its top.
+    ///
+    /// Once the climb starts it'll continue even if this is no longer set, and even if the action
+    /// is no longer fed.
+    pub climb_up: bool,
+
+    /// The maximum angle (in radians) between the normal of the ledge's top and the up direction
+    /// for the ledge to be grabbable.
+    pub max_slope_angle: Float,
+
+    /// How far below the ledge's top the character's center hangs.
+    pub hang_offset: Float,
+
+    /// The maximum speed for moving the character to its hanging height after grabbing the ledge.
+    pub snap_speed: Float,
+
+    /// How far above the ledge's top the character's center needs to rise when climbing up before
+    /// it can move forward onto the ledge. This should usually match the float height of the walk
+    /// basis.
+    pub climb_up_height: Float,
+
+    /// The speed of climbing up from the ledge.
+    pub climb_up_speed: Float,
+}
+
+impl Default for TnuaBuiltinLedgeGrab {
+    fn default() -> Self {
+        Self {
+            ledge_point: Vector3::ZERO,
+            ledge_normal: Vector3::ZERO,
+            ledge_direction: Vector3::ZERO,
+            desired_shimmy: 0.0,
+            climb_up: false,
+            max_slope_angle: 0.5,
+            hang_offset: 1.0,
+            snap_speed: 5.0,
+            climb_up_height: 1.5,
+            climb_up_speed: 4.0,
+        }
+    }
+}
+
+impl TnuaBuiltinLedgeGrab {
+    fn is_ledge_grabbable(&self, up: Vector3) -> bool {
+        if self.ledge_direction.reject_from(up) == Vector3::ZERO {
+            return false;
+        }
+        let Some(ledge_normal) = self.ledge_normal.try_normalize() else {
+            return false;
+        };
+        ledge_normal.dot(up).clamp(-1.0, 1.0).acos() <= self.max_slope_angle
+    }
+}
+
+impl TnuaAction for TnuaBuiltinLedgeGrab {
+    const NAME: &'static str = "TnuaBuiltinLedgeGrab";
+    type State = TnuaBuiltinLedgeGrabState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        let up = ctx.basis.up_direction().adjust_precision();
+        if ctx.basis.is_airborne() && self.is_ledge_grabbable(up) {
+            TnuaActionInitiationDirective::Allow
+        } else {
+            TnuaActionInitiationDirective::Delay
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        let up = ctx.basis.up_direction().adjust_precision();
+
+        if lifecycle_status.just_started() {
+            *state = TnuaBuiltinLedgeGrabState::Hanging {
+                ledge_point: self.ledge_point,
+                ledge_direction: self.ledge_direction.reject_from(up).normalize_or_zero(),
+            };
+        }
+
+        let position = ctx.tracker.translation;
+        let velocity = ctx.tracker.velocity;
+
+        // TODO: Once `std::mem::variant_count` gets stabilized, use that instead.
+        for _ in 0..3 {
+            return match state {
+                TnuaBuiltinLedgeGrabState::NoLedge => TnuaActionLifecycleDirective::Finished,
+                TnuaBuiltinLedgeGrabState::Hanging {
+                    ledge_point,
+                    ledge_direction,
+                } => {
+                    if !lifecycle_status.is_active() || self.ledge_normal == Vector3::ZERO {
+                        return TnuaActionLifecycleDirective::Finished;
+                    }
+                    if self.climb_up {
+                        *state = TnuaBuiltinLedgeGrabState::ClimbingUp {
+                            ledge_point: *ledge_point,
+                            ledge_direction: *ledge_direction,
+                        };
+                        continue;
+                    }
+                    let height_error = (*ledge_point - position).dot(up) - self.hang_offset;
+                    let vertical_velocity = (height_error / ctx.frame_duration)
+                        .clamp(-self.snap_speed, self.snap_speed);
+                    let right = ledge_direction.cross(up);
+                    let desired_velocity = vertical_velocity * up + self.desired_shimmy * right;
+                    motor.lin.acceleration = -ctx.tracker.gravity;
+                    motor.lin.boost = desired_velocity - velocity;
+                    TnuaActionLifecycleDirective::StillActive
+                }
+                TnuaBuiltinLedgeGrabState::ClimbingUp {
+                    ledge_point,
+                    ledge_direction,
+                } => {
+                    if matches!(lifecycle_status, TnuaActionLifecycleStatus::CancelledInto) {
+                        return TnuaActionLifecycleDirective::Finished;
+                    }
+                    let offset = position - *ledge_point;
+                    let desired_velocity = if offset.dot(up) < self.climb_up_height {
+                        self.climb_up_speed * up
+                    } else if offset.dot(*ledge_direction) < 0.0 {
+                        self.climb_up_speed * *ledge_direction
+                    } else {
+                        return TnuaActionLifecycleDirective::Finished;
+                    };
+                    motor.lin.acceleration = -ctx.tracker.gravity;
+                    motor.lin.boost = desired_velocity - velocity;
+                    TnuaActionLifecycleDirective::StillActive
+                }
+            };
+        }
+        error!("Tnua could not decide on ledge grab state");
+        TnuaActionLifecycleDirective::Finished
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub enum TnuaBuiltinLedgeGrabState {
+    #[default]
+    NoLedge,
+    /// The character hangs from the ledge.
+    Hanging {
+        /// The point on the top of the ledge that was grabbed.
+        ledge_point: Vector3,
+        /// The direction toward the ledge, orthogonal to the up direction.
+        ledge_direction: Vector3,
+    },
+    /// The character climbs from the ledge onto its top.
+    ClimbingUp {
+        /// The point on the top of the ledge that was grabbed.
+        ledge_point: Vector3,
+        /// The direction toward the ledge, orthogonal to the up direction.
+        ledge_direction: Vector3,
+    },
+}
@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+/// An [action](TnuaAction) for mantling (or vaulting) over a low obstacle.
+///
+/// The character moves from where it stood to the top of the obstacle along a short arc - rising
+/// first and moving forward later, so that it clears the obstacle's edge. The movement is done
+/// with the motor, so the physics engine still gets to resolve collisions on the way.
+///
+/// This action does not detect the obstacle by itself. The character entity needs a
+/// [`TnuaLedgeSensor`](crate::TnuaLedgeSensor) with its cast origin at the maximum height the
+/// character can mantle over and its cast direction in the movement direction, and the control
+/// system must feed its output into [`obstacle_point`](Self::obstacle_point) and
+/// [`obstacle_normal`](Self::obstacle_normal) and the cast direction into
+/// [`direction`](Self::direction).
+///
+/// To avoid mantling into a ceiling, the sensor's
+/// [`headroom_range`](crate::TnuaLedgeSensor::headroom_range) should be set to at least the
+/// [`required_headroom`](Self::required_headroom), and the control system should feed the
+/// [`headroom`](crate::TnuaLedgeSensorOutput::headroom) it detects into
+/// [`headroom`](Self::headroom):
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_tnua::prelude::*;
+/// # use bevy_tnua::builtins::TnuaBuiltinMantle;
+/// # use bevy_tnua::math::AdjustPrecision;
+/// # use bevy_tnua::TnuaLedgeSensor;
+/// fn player_control_system(mut query: Query<(&mut TnuaController, &TnuaLedgeSensor)>) {
+///     for (mut controller, ledge_sensor) in query.iter_mut() {
+///         let Some(obstacle) = ledge_sensor.output.as_ref() else {
+///             continue;
+///         };
+///         controller.action(TnuaBuiltinMantle {
+///             obstacle_point: obstacle.point,
+///             obstacle_normal: obstacle.normal.adjust_precision(),
+///             direction: ledge_sensor.cast_direction.adjust_precision(),
+///             headroom: obstacle.headroom,
+///             // The sensor's `headroom_range` should be at least that much.
+///             required_headroom: 2.0,
+///             ..Default::default()
+///         });
+///     }
+/// }
+/// ```
+///
+/// Once the mantle starts it'll continue until the character reaches the top of the obstacle,
+/// even if the action is no longer fed.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinMantle {
+    /// The point (in world space) on the top of the obstacle, as detected by the
+    /// [`TnuaLedgeSensor`](crate::TnuaLedgeSensor).
+    pub obstacle_point: Vector3,
+
+    /// The normal of the top of the obstacle, or `Vector3::ZERO` if the sensor did not detect an
+    /// obstacle.
+    pub obstacle_normal: Vector3,
+
+    /// The direction from the character toward the obstacle - usually the cast direction of the
+    /// [`TnuaLedgeSensor`](crate::TnuaLedgeSensor).
+    pub direction: Vector3,
+
+    /// The free space above the top of the obstacle, or [`Float::INFINITY`] if there is no
+    /// ceiling above it - usually the [`headroom`](crate::TnuaLedgeSensorOutput::headroom)
+    /// detected by the [`TnuaLedgeSensor`](crate::TnuaLedgeSensor).
+    pub headroom: Float,
+
+    /// The minimal [`headroom`](Self::headroom) required for mantling over the obstacle. This
+    /// should usually be the height of the character.
+    pub required_headroom: Float,
+
+    /// The minimal height of an obstacle, measured from the ground under the character, for the
+    /// character to mantle over it. Lower obstacles should be handled by the walk basis.
+    pub min_height: Float,
+
+    /// The maximal height of an obstacle, measured from the ground under the character, for the
+    /// character to mantle over it.
+    pub max_height: Float,
+
+    /// The maximum angle (in radians) between the normal of the obstacle's top and the up
+    /// direction for the character to be able to mantle over it.
+    pub max_slope_angle: Float,
+
+    /// How far above the obstacle's top the character's center should be at the end of the
+    /// mantle. This should usually match the float height of the walk basis.
+    pub end_height: Float,
+
+    /// How far past the [`obstacle_point`](Self::obstacle_point) the character should be at the
+    /// end of the mantle.
+    pub end_forward_distance: Float,
+
+    /// The time, in seconds, it takes to mantle over the obstacle.
+    pub duration: Float,
+}
+
+impl Default for TnuaBuiltinMantle {
+    fn default() -> Self {
+        Self {
+            obstacle_point: Vector3::ZERO,
+            obstacle_normal: Vector3::ZERO,
+            direction: Vector3::ZERO,
+            headroom: Float::INFINITY,
+            required_headroom: 0.0,
+            min_height: 0.3,
+            max_height: 1.2,
+            max_slope_angle: 0.5,
+            end_height: 1.5,
+            end_forward_distance: 0.0,
+            duration: 0.4,
+        }
+    }
+}
+
+impl TnuaBuiltinMantle {
+    fn obstacle_height(&self, ctx: &TnuaActionContext, up: Vector3) -> Option<Float> {
+        if self.direction.reject_from(up) == Vector3::ZERO {
+            return None;
+        }
+        let obstacle_normal = self.obstacle_normal.try_normalize()?;
+        if self.max_slope_angle < obstacle_normal.dot(up).clamp(-1.0, 1.0).acos() {
+            return None;
+        }
+        let ground = ctx.proximity_sensor.output.as_ref()?;
+        // The ground proximity is measured from the proximity sensor's cast origin, not from the
+        // character's center.
+        let proximity_cast_origin =
+            ctx.tracker.translation + ctx.tracker.rotation * ctx.proximity_sensor.cast_origin;
+        Some((self.obstacle_point - proximity_cast_origin).dot(up) + ground.proximity)
+    }
+}
+
+impl TnuaAction for TnuaBuiltinMantle {
+    const NAME: &'static str = "TnuaBuiltinMantle";
+    type State = TnuaBuiltinMantleState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if ctx.basis.is_airborne() || self.headroom < self.required_headroom {
+            return TnuaActionInitiationDirective::Delay;
+        }
+        let up = ctx.basis.up_direction().adjust_precision();
+        match self.obstacle_height(&ctx, up) {
+            Some(height) if (self.min_height..=self.max_height).contains(&height) => {
+                TnuaActionInitiationDirective::Allow
+            }
+            _ => TnuaActionInitiationDirective::Delay,
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        let up = ctx.basis.up_direction().adjust_precision();
+
+        if lifecycle_status.just_started() {
+            let start = ctx.tracker.translation;
+            let direction = self.direction.reject_from(up).normalize_or_zero();
+            let end =
+                self.obstacle_point + self.end_height * up + self.end_forward_distance * direction;
+            *state = TnuaBuiltinMantleState {
+                start,
+                // Rise above the starting point before moving forward, so that the character
+                // will clear the obstacle's edge.
+                control: start + (end - start).project_onto(up),
+                end,
+                elapsed: 0.0,
+                duration: self.duration,
+            };
+        }
+
+        if matches!(lifecycle_status, TnuaActionLifecycleStatus::CancelledInto)
+            || state.duration <= state.elapsed
+        {
+            return TnuaActionLifecycleDirective::Finished;
+        }
+
+        state.elapsed += ctx.frame_duration;
+        let target = state.position_at(state.progress());
+        motor.lin.acceleration = -ctx.tracker.gravity;
+        motor.lin.boost =
+            (target - ctx.tracker.translation) / ctx.frame_duration - ctx.tracker.velocity;
+
+        TnuaActionLifecycleDirective::StillActive
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinMantleState {
+    start: Vector3,
+    control: Vector3,
+    end: Vector3,
+    elapsed: Float,
+    duration: Float,
+}
+
+impl TnuaBuiltinMantleState {
+    /// How much of the mantle was done, from 0.0 when it starts to 1.0 when the character reaches
+    /// the top of the obstacle.
+    ///
+    /// Useful for syncing the mantle animation with the character's movement.
+    pub fn progress(&self) -> Float {
+        if self.duration <= 0.0 {
+            1.0
+        } else {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        }
+    }
+
+    fn position_at(&self, progress: Float) -> Vector3 {
+        // A quadratic Bezier curve
+        let rest = 1.0 - progress;
+        rest * rest * self.start
+            + 2.0 * rest * progress * self.control
+            + progress * progress * self.end
+    }
+}
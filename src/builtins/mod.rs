@@ -1,9 +1,40 @@
+mod charge_jump;
+mod climb;
 mod crouch;
 mod dash;
+mod directional_jump;
+mod free_flight;
+mod glide;
+mod ground_pound;
 mod jump;
+mod knockback;
+mod ledge_grab;
+mod mantle;
+mod slide;
+mod swim;
+mod swing;
 mod walk;
+mod wall_jump;
+mod wall_run;
+mod wall_slide;
 
+pub use crate::TnuaSurface;
+pub use charge_jump::{TnuaBuiltinChargeJump, TnuaBuiltinChargeJumpState};
+pub use climb::{TnuaBuiltinClimb, TnuaBuiltinClimbExit, TnuaBuiltinClimbState, TnuaClimbable};
 pub use crouch::{TnuaBuiltinCrouch, TnuaBuiltinCrouchState};
 pub use dash::{TnuaBuiltinDash, TnuaBuiltinDashState};
+pub use directional_jump::{TnuaBuiltinDirectionalJump, TnuaBuiltinDirectionalJumpState};
+pub use free_flight::{TnuaBuiltinFreeFlight, TnuaBuiltinFreeFlightState};
+pub use glide::{TnuaBuiltinGlide, TnuaBuiltinGlideState};
+pub use ground_pound::{TnuaBuiltinGroundPound, TnuaBuiltinGroundPoundState};
 pub use jump::{TnuaBuiltinJump, TnuaBuiltinJumpState};
-pub use walk::{TnuaBuiltinWalk, TnuaBuiltinWalkState};
+pub use knockback::{TnuaBuiltinKnockback, TnuaBuiltinKnockbackState};
+pub use ledge_grab::{TnuaBuiltinLedgeGrab, TnuaBuiltinLedgeGrabState};
+pub use mantle::{TnuaBuiltinMantle, TnuaBuiltinMantleState};
+pub use slide::{TnuaBuiltinSlide, TnuaBuiltinSlideState};
+pub use swim::{TnuaBuiltinSwim, TnuaBuiltinSwimState};
+pub use swing::{TnuaBuiltinSwing, TnuaBuiltinSwingState};
+pub use walk::{TnuaBuiltinWalk, TnuaBuiltinWalkState, TnuaGroundContactChange};
+pub use wall_jump::{TnuaBuiltinWallJump, TnuaBuiltinWallJumpState};
+pub use wall_run::{TnuaBuiltinWallRun, TnuaBuiltinWallRunSide, TnuaBuiltinWallRunState};
+pub use wall_slide::{TnuaBuiltinWallSlide, TnuaBuiltinWallSlideState};
@@ -0,0 +1,248 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float};
+
+use crate::basis_action_traits::{
+    TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus,
+};
+use crate::control_helpers::TnuaCrouchEnforcedAction;
+use crate::{TnuaAction, TnuaMotor, TnuaVelChange};
+
+use super::TnuaBuiltinWalk;
+
+/// An [action](TnuaAction) for sliding on the ground. Only works when [`TnuaBuiltinWalk`] is the
+/// [basis](crate::TnuaBasis).
+///
+/// Like [`TnuaBuiltinCrouch`](crate::builtins::TnuaBuiltinCrouch), this action lowers the
+/// character by [`float_offset`](Self::float_offset). Unlike crouching, the character does not
+/// slow down to the walk basis' speed - instead it keeps its momentum, which slowly decays by
+/// [`friction`](Self::friction), and it accelerates when sliding downhill. The walk basis' desired
+/// velocity is ignored while sliding.
+///
+/// The slide can only start when the character is on the ground and moving at least at
+/// [`min_speed`](Self::min_speed), and it finishes when the character slows down below that speed
+/// or leaves the ground. If the player still wants to crouch at that point, the control system
+/// should feed [`TnuaBuiltinCrouch`](crate::builtins::TnuaBuiltinCrouch) instead - it'll keep the
+/// character low since it's already lowered. If the slide is no longer fed, the character will
+/// stand up.
+///
+/// To prevent the character from standing up under an obstacle, use this action together with
+/// [`TnuaCrouchEnforcer`](crate::control_helpers::TnuaCrouchEnforcer). While it is enforced,
+/// the slide will keep the character low even after it slows down.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinSlide {
+    /// Controls how low the character will slide, compared to its regular float offset while
+    /// standing.
+    ///
+    /// See [`TnuaBuiltinCrouch::float_offset`](crate::builtins::TnuaBuiltinCrouch::float_offset).
+    pub float_offset: Float,
+
+    /// A duration, in seconds, that it should take for the character to change its floating height
+    /// to start or stop the slide.
+    ///
+    /// Set this to more than the expected duration of a single frame, so that the character will
+    /// sink (or rise) over a few frames instead of being teleported, and the
+    /// [`spring_dampening`](crate::builtins::TnuaBuiltinWalk::spring_dampening) force will have
+    /// time to stop the vertical movement once it reaches the new height.
+    pub height_change_impulse_for_duration: Float,
+
+    /// The maximum impulse to apply when starting or stopping the slide.
+    pub height_change_impulse_limit: Float,
+
+    /// The deceleration of the character while sliding.
+    pub friction: Float,
+
+    /// The minimal speed for sliding. The slide cannot start if the character is slower than
+    /// this, and it finishes once the character slows below it.
+    pub min_speed: Float,
+
+    /// If set to `true`, this action will not yield to other action who try to take control, and
+    /// will not finish when the character slows down.
+    ///
+    /// This is set by [`TnuaCrouchEnforcer`](crate::control_helpers::TnuaCrouchEnforcer) while
+    /// the character is under an obstacle.
+    pub uncancellable: bool,
+}
+
+impl Default for TnuaBuiltinSlide {
+    fn default() -> Self {
+        Self {
+            float_offset: 0.0,
+            height_change_impulse_for_duration: 0.02,
+            height_change_impulse_limit: 40.0,
+            friction: 3.0,
+            min_speed: 2.0,
+            uncancellable: false,
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinSlide {
+    const NAME: &'static str = "TnuaBuiltinSlide";
+    type State = TnuaBuiltinSlideState;
+    const VIOLATES_COYOTE_TIME: bool = false;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        let up = ctx.basis.up_direction().adjust_precision();
+        if ctx.proximity_sensor.output.is_some()
+            && !ctx.basis.is_airborne()
+            && self.min_speed <= ctx.basis.effective_velocity().reject_from(up).length()
+        {
+            TnuaActionInitiationDirective::Allow
+        } else {
+            TnuaActionInitiationDirective::Delay
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        let Some((walk_basis, walk_state)) = ctx.concrete_basis::<TnuaBuiltinWalk>() else {
+            error!("Cannot slide - basis is not TnuaBuiltinWalk");
+            return TnuaActionLifecycleDirective::Finished;
+        };
+        let Some(sensor_output) = &ctx.proximity_sensor.output else {
+            return TnuaActionLifecycleDirective::Finished;
+        };
+        // The sensor may still detect the ground after the character left it.
+        if ctx.basis.is_airborne() {
+            return TnuaActionLifecycleDirective::Finished;
+        }
+        let up = walk_basis.up.adjust_precision();
+        let spring_offset_up = walk_basis.float_height - sensor_output.proximity.adjust_precision();
+        let spring_offset_down = spring_offset_up + self.float_offset;
+
+        match lifecycle_status {
+            TnuaActionLifecycleStatus::Initiated => {}
+            TnuaActionLifecycleStatus::CancelledFrom => {}
+            TnuaActionLifecycleStatus::StillFed => {}
+            TnuaActionLifecycleStatus::NoLongerFed => {
+                *state = TnuaBuiltinSlideState::Rising;
+            }
+            TnuaActionLifecycleStatus::CancelledInto => {
+                if !self.uncancellable {
+                    *state = TnuaBuiltinSlideState::Rising;
+                }
+            }
+        }
+
+        if matches!(
+            state,
+            TnuaBuiltinSlideState::Sinking | TnuaBuiltinSlideState::Sliding
+        ) {
+            let planar_velocity = ctx.basis.effective_velocity().reject_from(up);
+            if planar_velocity.length() < self.min_speed && !self.uncancellable {
+                return TnuaActionLifecycleDirective::Finished;
+            }
+
+            // Replace the horizontal movement of the walk basis with the slide's own.
+            motor.lin.boost = motor.lin.boost.project_onto(up);
+            motor.lin.acceleration = motor.lin.acceleration.project_onto(up);
+            let friction_boost =
+                planar_velocity.clamp_length_max(self.friction * ctx.frame_duration);
+            let downhill_acceleration = ctx
+                .tracker
+                .gravity
+                .reject_from(sensor_output.normal.adjust_precision())
+                .reject_from(up);
+            motor.lin += TnuaVelChange {
+                acceleration: downhill_acceleration,
+                boost: -friction_boost,
+            };
+        }
+
+        let spring_force_boost = |spring_offset: Float| -> Float {
+            walk_basis.spring_force_boost(walk_state, &ctx.as_basis_context(), spring_offset)
+        };
+
+        let impulse_or_spring_force_boost = |spring_offset: Float| -> Float {
+            let spring_force_boost = spring_force_boost(spring_offset);
+            let impulse_boost = self.impulse_boost(spring_offset);
+            if spring_force_boost.abs() < impulse_boost.abs() {
+                impulse_boost
+            } else {
+                spring_force_boost
+            }
+        };
+
+        let mut set_impulse = |impulse: Float| {
+            motor.lin.cancel_on_axis(up);
+            motor.lin += TnuaVelChange::boost(impulse * up);
+        };
+
+        match state {
+            TnuaBuiltinSlideState::Sinking => {
+                if spring_offset_down < -0.01 {
+                    set_impulse(impulse_or_spring_force_boost(spring_offset_down));
+                } else {
+                    *state = TnuaBuiltinSlideState::Sliding;
+                    set_impulse(spring_force_boost(spring_offset_down));
+                }
+                lifecycle_status.directive_simple()
+            }
+            TnuaBuiltinSlideState::Sliding => {
+                set_impulse(spring_force_boost(spring_offset_down));
+                // If it's finished/cancelled, something else should changed its state
+                TnuaActionLifecycleDirective::StillActive
+            }
+            TnuaBuiltinSlideState::Rising => {
+                if 0.01 < spring_offset_up {
+                    set_impulse(impulse_or_spring_force_boost(spring_offset_up));
+
+                    if matches!(lifecycle_status, TnuaActionLifecycleStatus::CancelledInto) {
+                        // Don't finish the rise - just do the other action
+                        TnuaActionLifecycleDirective::Reschedule { after_seconds: 0.0 }
+                    } else {
+                        // Finish the rise
+                        TnuaActionLifecycleDirective::StillActive
+                    }
+                } else {
+                    TnuaActionLifecycleDirective::Finished
+                }
+            }
+        }
+    }
+}
+
+impl TnuaBuiltinSlide {
+    fn impulse_boost(&self, spring_offset: Float) -> Float {
+        let velocity_to_get_to_new_float_height =
+            spring_offset / self.height_change_impulse_for_duration;
+        velocity_to_get_to_new_float_height.clamp(
+            -self.height_change_impulse_limit,
+            self.height_change_impulse_limit,
+        )
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub enum TnuaBuiltinSlideState {
+    /// The character is transitioning from standing to sliding.
+    #[default]
+    Sinking,
+    /// The character is currently sliding.
+    Sliding,
+    /// The character is transitioning from sliding to standing.
+    Rising,
+}
+
+impl TnuaCrouchEnforcedAction for TnuaBuiltinSlide {
+    fn range_to_cast_up(&self, _state: &Self::State) -> Float {
+        -self.float_offset
+    }
+
+    fn prevent_cancellation(&mut self) {
+        self.uncancellable = true;
+    }
+}
@@ -0,0 +1,242 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::basis_action_traits::TnuaBasisContext;
+use crate::util::upright_and_turning_boost;
+use crate::{TnuaBasis, TnuaVelChange};
+
+/// A [basis](TnuaBasis) for swimming inside a [`TnuaWaterVolume`](crate::TnuaWaterVolume).
+///
+/// While in water this basis cancels gravity, applies drag, and moves the character toward the
+/// [`desired_velocity`](Self::desired_velocity) - which, unlike in
+/// [`TnuaBuiltinWalk`](crate::builtins::TnuaBuiltinWalk), may have a vertical component. When
+/// there is no vertical input, the character floats near the surface at
+/// [`float_depth`](Self::float_depth).
+///
+/// This basis does not detect the water by itself. The character entity needs a
+/// [`TnuaWaterSensor`](crate::TnuaWaterSensor), and the control system must feed its depth into
+/// [`water_depth`](Self::water_depth). The control system is also responsible for switching
+/// between this basis and the walk basis - when the character is deep enough in the water it
+/// should switch to this basis, and when [`should_exit`](TnuaBuiltinSwimState::should_exit)
+/// returns `true` it should switch back to walking:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_tnua::prelude::*;
+/// # use bevy_tnua::builtins::TnuaBuiltinSwim;
+/// # use bevy_tnua::TnuaWaterSensor;
+/// # use bevy_tnua::math::Vector3;
+/// # let mut controller = TnuaController::default();
+/// # let water_sensor = TnuaWaterSensor::default();
+/// # let direction = Vector3::ZERO;
+/// let water_depth = water_sensor.output.as_ref().map_or(0.0, |output| output.depth);
+/// let swimming = if let Some((_, swim_state)) = controller.concrete_basis::<TnuaBuiltinSwim>() {
+///     !swim_state.should_exit()
+/// } else {
+///     // Deeper than the float depth, so that walking in shallow water will not start swimming.
+///     1.0 < water_depth
+/// };
+/// if swimming {
+///     controller.basis(TnuaBuiltinSwim {
+///         desired_velocity: direction * 5.0,
+///         desired_forward: direction.reject_from(Vector3::Y),
+///         water_depth,
+///         exit_ground_distance: 1.5,
+///         ..Default::default()
+///     });
+/// } else {
+///     controller.basis(TnuaBuiltinWalk {
+///         desired_velocity: direction.reject_from(Vector3::Y) * 10.0,
+///         float_height: 1.5,
+///         ..Default::default()
+///     });
+/// }
+/// ```
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinSwim {
+    /// The direction (in the world space) and speed to accelerate to.
+    ///
+    /// The vertical component of this vector is used for diving and for swimming back up. Note
+    /// that the character cannot swim up above the [`float_depth`](Self::float_depth).
+    pub desired_velocity: Vector3,
+
+    /// If non-zero, Tnua will rotate the character so that its negative Z will face in that
+    /// direction.
+    ///
+    /// Tnua assumes that this vector is orthogonal to the [`up`](Self::up) vector.
+    pub desired_forward: Vector3,
+
+    /// How deep the character's origin is below the water surface.
+    ///
+    /// This should be fed every frame from the [`depth`](crate::TnuaWaterSensorOutput::depth) of
+    /// the character's [`TnuaWaterSensor`](crate::TnuaWaterSensor), or `0.0` if the sensor does
+    /// not detect any water.
+    pub water_depth: Float,
+
+    /// The direction considered as upward.
+    ///
+    /// Typically `Vector3::Y`.
+    pub up: Direction3d,
+
+    /// The depth, below the water surface, at which the character's origin floats when there is
+    /// no vertical input.
+    pub float_depth: Float,
+
+    /// The vertical speed (per unit of distance from the [`float_depth`](Self::float_depth)) used
+    /// for bringing the character back to the float depth.
+    pub buoyancy: Float,
+
+    /// The maximum acceleration for reaching the desired velocity.
+    pub acceleration: Float,
+
+    /// The fraction of the velocity the water takes away every second.
+    ///
+    /// The drag is applied before the [`acceleration`](Self::acceleration), so it mostly affects
+    /// characters that move faster than they could swim - e.g. when they fall into the water.
+    pub drag: Float,
+
+    /// When ground is detected closer than this, the character is considered to have reached the
+    /// shore and [`should_exit`](TnuaBuiltinSwimState::should_exit) will return `true`.
+    ///
+    /// This should be a bit more than the [float
+    /// height](crate::builtins::TnuaBuiltinWalk::float_height) of the walk basis.
+    pub exit_ground_distance: Float,
+
+    /// The maximum angular velocity used for keeping the character upright.
+    pub tilt_offset_angvel: Float,
+
+    /// The maximum angular acceleration used for reaching `tilt_offset_angvel`.
+    pub tilt_offset_angacl: Float,
+
+    /// The maximum angular velocity used for turning the character when the direction changes.
+    pub turning_angvel: Float,
+}
+
+impl Default for TnuaBuiltinSwim {
+    fn default() -> Self {
+        Self {
+            desired_velocity: Vector3::ZERO,
+            desired_forward: Vector3::ZERO,
+            water_depth: 0.0,
+            up: Direction3d::Y,
+            float_depth: 0.5,
+            buoyancy: 4.0,
+            acceleration: 30.0,
+            drag: 2.0,
+            exit_ground_distance: 0.0,
+            tilt_offset_angvel: 5.0,
+            tilt_offset_angacl: 500.0,
+            turning_angvel: 10.0,
+        }
+    }
+}
+
+impl TnuaBasis for TnuaBuiltinSwim {
+    const NAME: &'static str = "TnuaBuiltinSwim";
+    type State = TnuaBuiltinSwimState;
+
+    fn apply(&self, state: &mut Self::State, ctx: TnuaBasisContext, motor: &mut crate::TnuaMotor) {
+        let up = self.up.adjust_precision();
+        state.water_depth = self.water_depth;
+        state.effective_velocity = ctx.tracker.velocity;
+        state.ground_in_reach = ctx
+            .proximity_sensor
+            .output
+            .as_ref()
+            .is_some_and(|output| output.proximity <= self.exit_ground_distance);
+
+        motor.lin = if self.water_depth <= 0.0 {
+            // Out of the water - let gravity do its thing
+            TnuaVelChange::ZERO
+        } else {
+            let surfacing_speed = self.buoyancy * (self.water_depth - self.float_depth);
+            let desired_vertical = self.desired_velocity.dot(up);
+            let target_vertical = if desired_vertical == 0.0 {
+                surfacing_speed
+            } else if 0.0 < desired_vertical {
+                desired_vertical.min(surfacing_speed.max(0.0))
+            } else {
+                desired_vertical
+            };
+            let target_velocity = self.desired_velocity.reject_from(up) + target_vertical * up;
+
+            let velocity = ctx.tracker.velocity;
+            let velocity_after_drag = velocity * (1.0 - (self.drag * ctx.frame_duration).min(1.0));
+            let control_boost = (target_velocity - velocity_after_drag)
+                .clamp_length_max(self.acceleration * ctx.frame_duration);
+
+            TnuaVelChange {
+                acceleration: -ctx.tracker.gravity,
+                boost: velocity_after_drag - velocity + control_boost,
+            }
+        };
+
+        motor.ang = TnuaVelChange::boost(upright_and_turning_boost(
+            ctx.tracker,
+            ctx.frame_duration,
+            self.up,
+            self.desired_forward,
+            self.tilt_offset_angvel,
+            self.tilt_offset_angacl,
+            self.turning_angvel,
+        ));
+    }
+
+    fn proximity_sensor_cast_range(&self, _state: &Self::State) -> Float {
+        self.exit_ground_distance
+    }
+
+    fn up_direction(&self, _state: &Self::State) -> Direction3d {
+        self.up
+    }
+
+    fn displacement(&self, state: &Self::State) -> Option<Vector3> {
+        if 0.0 < state.water_depth {
+            Some(self.up.adjust_precision() * (self.float_depth - state.water_depth))
+        } else {
+            None
+        }
+    }
+
+    fn effective_velocity(&self, state: &Self::State) -> Vector3 {
+        state.effective_velocity
+    }
+
+    fn vertical_velocity(&self, _state: &Self::State) -> Float {
+        0.0
+    }
+
+    fn neutralize(&mut self) {
+        self.desired_velocity = Vector3::ZERO;
+        self.desired_forward = Vector3::ZERO;
+    }
+
+    fn is_airborne(&self, state: &Self::State) -> bool {
+        state.water_depth <= 0.0
+    }
+
+    fn violate_coyote_time(&self, _state: &mut Self::State) {}
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinSwimState {
+    effective_velocity: Vector3,
+    water_depth: Float,
+    ground_in_reach: bool,
+}
+
+impl TnuaBuiltinSwimState {
+    /// Check if the character should stop swimming - either because it left the water or because
+    /// it reached ground it can walk on.
+    pub fn should_exit(&self) -> bool {
+        self.water_depth <= 0.0 || self.ground_in_reach
+    }
+
+    /// The depth of the character below the water surface, as it was fed to the basis.
+    pub fn water_depth(&self) -> Float {
+        self.water_depth
+    }
+}
@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+/// An [action](TnuaAction) for swinging from a rope (or a grappling hook) attached to an anchor
+/// point.
+///
+/// The rope keeps the character from getting farther than the rope's length from the
+/// [`anchor`](Self::anchor), but does not prevent it from getting closer. The rope's length starts
+/// as the distance between the character and the anchor when the action starts, and can be
+/// changed by reeling it in or out with [`reel_speed`](Self::reel_speed).
+///
+/// While in the air, the basis' movement is replaced by the swing - the player can pump the swing
+/// by pointing [`desired_velocity`](Self::desired_velocity) in the direction of the swing. While
+/// on the ground, the basis keeps controlling the character and the rope only restricts its
+/// movement.
+///
+/// The character is released from the rope once the action is no longer fed, and it keeps the
+/// momentum it had when it was released - the action stays active (without affecting the
+/// character) until the character stops going up, so that the basis will not slow it down.
+///
+/// Since only the anchor and the up direction matter, this action works for both 2D and 3D games.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinSwing {
+    /// The point (in world space) the rope is attached to.
+    pub anchor: Vector3,
+
+    /// The direction the player wants to swing toward.
+    ///
+    /// This is usually the same as [`TnuaBuiltinWalk::desired_velocity`](crate::builtins::TnuaBuiltinWalk::desired_velocity).
+    /// Only its direction matters - its magnitude is ignored.
+    pub desired_velocity: Vector3,
+
+    /// The speed for changing the rope's length. Positive values reel the rope in (pulling the
+    /// character toward the anchor) and negative values reel it out.
+    pub reel_speed: Float,
+
+    /// The maximum length of the rope. The action cannot start if the anchor is farther than this.
+    pub max_rope_length: Float,
+
+    /// The minimal length the rope can be reeled in to.
+    pub min_rope_length: Float,
+
+    /// The acceleration for pumping the swing.
+    pub pump_acceleration: Float,
+}
+
+impl Default for TnuaBuiltinSwing {
+    fn default() -> Self {
+        Self {
+            anchor: Vector3::ZERO,
+            desired_velocity: Vector3::ZERO,
+            reel_speed: 0.0,
+            max_rope_length: 10.0,
+            min_rope_length: 1.0,
+            pump_acceleration: 5.0,
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinSwing {
+    const NAME: &'static str = "TnuaBuiltinSwing";
+    type State = TnuaBuiltinSwingState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if self.anchor.is_finite()
+            && ctx.tracker.translation.distance(self.anchor) <= self.max_rope_length
+        {
+            TnuaActionInitiationDirective::Allow
+        } else {
+            TnuaActionInitiationDirective::Reject
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        let up = ctx.basis.up_direction().adjust_precision();
+        let is_airborne = ctx.basis.is_airborne();
+
+        let from_anchor = ctx.tracker.translation - self.anchor;
+        let distance = from_anchor.length();
+        let min_rope_length = self.min_rope_length.min(self.max_rope_length);
+
+        if lifecycle_status.just_started() {
+            *state = TnuaBuiltinSwingState {
+                rope_length: distance,
+                ..Default::default()
+            };
+        } else {
+            state.rope_length -= self.reel_speed * ctx.frame_duration;
+        }
+
+        match lifecycle_status {
+            TnuaActionLifecycleStatus::Initiated => {}
+            TnuaActionLifecycleStatus::CancelledFrom => {}
+            TnuaActionLifecycleStatus::StillFed => {}
+            TnuaActionLifecycleStatus::NoLongerFed => {
+                state.released = true;
+            }
+            TnuaActionLifecycleStatus::CancelledInto => {
+                return TnuaActionLifecycleDirective::Finished;
+            }
+        }
+
+        if state.released {
+            // Keep the basis from slowing down the character until it stops going up.
+            if !is_airborne || ctx.basis.effective_velocity().dot(up) <= 0.0 {
+                return TnuaActionLifecycleDirective::Finished;
+            }
+            motor.lin = Default::default();
+            return TnuaActionLifecycleDirective::StillActive;
+        }
+
+        state.rope_length = state
+            .rope_length
+            .clamp(min_rope_length, self.max_rope_length);
+        state.anchor = self.anchor;
+
+        if is_airborne {
+            motor.lin = Default::default();
+        }
+
+        let Some(rope_direction) = from_anchor.try_normalize() else {
+            return TnuaActionLifecycleDirective::StillActive;
+        };
+
+        if is_airborne {
+            let pump_direction = self
+                .desired_velocity
+                .reject_from(rope_direction)
+                .normalize_or_zero();
+            // Only pump when hanging below the anchor, so that the rope will not go slack.
+            if rope_direction.dot(up) < 0.0 {
+                motor.lin.acceleration += self.pump_acceleration * pump_direction;
+            }
+        }
+
+        if state.rope_length <= distance {
+            let velocity = ctx.tracker.velocity + motor.lin.boost;
+            // Pull the character back to the rope's length (if it got too far) and cancel the
+            // velocity that takes it away from the anchor.
+            let max_outward_speed = (state.rope_length - distance) / ctx.frame_duration;
+            let outward_speed = velocity.dot(rope_direction);
+            if max_outward_speed < outward_speed {
+                motor.lin.boost -= (outward_speed - max_outward_speed) * rope_direction;
+            }
+            let outward_acceleration =
+                (ctx.tracker.gravity + motor.lin.acceleration).dot(rope_direction);
+            if 0.0 < outward_acceleration {
+                motor.lin.acceleration -= outward_acceleration * rope_direction;
+            }
+        }
+
+        TnuaActionLifecycleDirective::StillActive
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinSwingState {
+    anchor: Vector3,
+    rope_length: Float,
+    released: bool,
+}
+
+impl TnuaBuiltinSwingState {
+    /// The point (in world space) the rope is attached to.
+    pub fn anchor(&self) -> Vector3 {
+        self.anchor
+    }
+
+    /// The current length of the rope.
+    ///
+    /// Note that the rope may be slack, so the character may be closer to the anchor than this.
+    pub fn rope_length(&self) -> Float {
+        self.rope_length
+    }
+
+    /// Check if the character was released from the rope.
+    pub fn is_released(&self) -> bool {
+        self.released
+    }
+}
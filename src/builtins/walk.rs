@@ -1,10 +1,10 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
-use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Quaternion, Vector3};
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
 
 use crate::basis_action_traits::TnuaBasisContext;
-use crate::util::ProjectionPlaneForRotation;
+use crate::util::upright_and_turning_boost;
 use crate::{TnuaBasis, TnuaVelChange};
 
 /// The most common [basis](TnuaBasis) - walk around as a floating capsule.
@@ -32,7 +32,10 @@ use crate::{TnuaBasis, TnuaVelChange};
 ///   leaving `desired_forward` as the default `Vector3::ZERO` may cause the character to spin
 ///   uncontrollably when it contacts other colliders. Unless, of course, some other mechanism
 ///   prevents that.
-#[derive(Clone)]
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct TnuaBuiltinWalk {
     /// The direction (in the world space) and speed to accelerate to.
     ///
@@ -62,6 +65,35 @@ pub struct TnuaBuiltinWalk {
     /// above the `float_height`, Tnua will consider it to be in the air.
     pub cling_distance: Float,
 
+    /// The height of the highest step the character can walk up onto.
+    ///
+    /// Set to 0.0 (the default) to disable stepping up. Stepping up also requires a
+    /// [`TnuaStepSensor`](crate::TnuaStepSensor) on the character entity, and the control system
+    /// must feed its output into [`step_point`](Self::step_point) and
+    /// [`step_normal`](Self::step_normal). When a step no higher than `max_step_height` is
+    /// detected in the direction of [`desired_velocity`](Self::desired_velocity), the spring will
+    /// raise the character so that it'll float above the step instead of bumping into it.
+    pub max_step_height: Float,
+
+    /// The point (in world space) on the top of the step in front of the character, as detected by
+    /// the [`TnuaStepSensor`](crate::TnuaStepSensor).
+    pub step_point: Vector3,
+
+    /// The normal of the top of the step, or `Vector3::ZERO` if the sensor did not detect a step.
+    pub step_normal: Vector3,
+
+    /// The maximum angle (in radians) between the ground's normal and the [`up`](Self::up)
+    /// direction for the ground to be walkable.
+    ///
+    /// Steeper ground is not considered as ground - the spring does not hold the character up and
+    /// the character loses its footing (just like when it walks off a ledge), so gravity makes it
+    /// slide down along the slope (assuming the physics backend resolves the collision with the
+    /// slope). While on such a slope, the character cannot be moved uphill, and
+    /// [`TnuaBuiltinWalkState::on_steep_slope`] reports it.
+    ///
+    /// Defaults to a right angle, which means all the ground is walkable.
+    pub max_slope_angle: Float,
+
     /// The direction considered as upward.
     ///
     /// Typically `Vector3::Y`.
@@ -124,12 +156,17 @@ pub struct TnuaBuiltinWalk {
 }
 
 impl Default for TnuaBuiltinWalk {
+    #[allow(clippy::unnecessary_cast)]
     fn default() -> Self {
         Self {
             desired_velocity: Vector3::ZERO,
             desired_forward: Vector3::ZERO,
             float_height: 0.0,
             cling_distance: 1.0,
+            max_step_height: 0.0,
+            step_point: Vector3::ZERO,
+            step_normal: Vector3::ZERO,
+            max_slope_angle: std::f64::consts::FRAC_PI_2 as Float,
             up: Direction3d::Y,
             spring_strengh: 400.0,
             spring_dampening: 1.2,
@@ -151,7 +188,10 @@ impl TnuaBasis for TnuaBuiltinWalk {
     fn apply(&self, state: &mut Self::State, ctx: TnuaBasisContext, motor: &mut crate::TnuaMotor) {
         if let Some(stopwatch) = &mut state.airborne_timer {
             stopwatch.tick(Duration::from_secs_f64(ctx.frame_duration as f64));
+            state.airtime += ctx.frame_duration;
         }
+        state.ground_contact_change = None;
+        let previously_standing_on = state.standing_on_entity();
 
         let climb_vectors: Option<ClimbVectors>;
         let considered_in_air: bool;
@@ -159,8 +199,15 @@ impl TnuaBasis for TnuaBuiltinWalk {
 
         if let Some(sensor_output) = &ctx.proximity_sensor.output {
             state.effective_velocity = ctx.tracker.velocity - sensor_output.entity_linvel;
+            state.on_steep_slope = self.max_slope_angle
+                < sensor_output
+                    .normal
+                    .adjust_precision()
+                    .dot(self.up.adjust_precision())
+                    .clamp(-1.0, 1.0)
+                    .acos();
             let sideways_unnormalized = sensor_output.normal.cross(*self.up).adjust_precision();
-            if sideways_unnormalized == Vector3::ZERO {
+            if sideways_unnormalized == Vector3::ZERO || state.on_steep_slope {
                 climb_vectors = None;
             } else {
                 climb_vectors = Some(ClimbVectors {
@@ -171,7 +218,7 @@ impl TnuaBasis for TnuaBuiltinWalk {
                     sideways: sideways_unnormalized.normalize_or_zero().adjust_precision(),
                 });
             }
-            considered_in_air = state.airborne_timer.is_some();
+            considered_in_air = state.airborne_timer.is_some() || state.on_steep_slope;
             if considered_in_air {
                 impulse_to_offset = Vector3::ZERO;
                 state.standing_on = None;
@@ -193,6 +240,7 @@ impl TnuaBasis for TnuaBuiltinWalk {
             }
         } else {
             state.effective_velocity = ctx.tracker.velocity;
+            state.on_steep_slope = false;
             climb_vectors = None;
             considered_in_air = true;
             impulse_to_offset = Vector3::ZERO;
@@ -204,22 +252,32 @@ impl TnuaBasis for TnuaBuiltinWalk {
             .effective_velocity
             .reject_from(self.up.adjust_precision());
 
-        let desired_boost = self.desired_velocity - velocity_on_plane;
+        let surface = ctx.surface.filter(|_| !considered_in_air);
+        let desired_velocity =
+            self.desired_velocity * surface.map_or(1.0, |surface| surface.max_speed_multiplier);
+
+        let desired_boost = desired_velocity - velocity_on_plane;
 
-        let safe_direction_coefficient = self
-            .desired_velocity
+        let safe_direction_coefficient = desired_velocity
             .normalize_or_zero()
             .dot(velocity_on_plane.normalize_or_zero());
         let direction_change_factor = 1.5 - 0.5 * safe_direction_coefficient;
 
         let relevant_acceleration_limit = if considered_in_air {
             self.air_acceleration
+        } else if let Some(surface) = surface {
+            self.acceleration
+                * if desired_velocity == Vector3::ZERO {
+                    surface.friction_multiplier
+                } else {
+                    surface.acceleration_multiplier
+                }
         } else {
             self.acceleration
         };
         let max_acceleration = direction_change_factor * relevant_acceleration_limit;
 
-        let walk_vel_change = if self.desired_velocity == Vector3::ZERO {
+        let walk_vel_change = if desired_velocity == Vector3::ZERO {
             // When stopping, prefer a boost to be able to reach a precise stop (see issue #39)
             let walk_boost = desired_boost.clamp_length_max(ctx.frame_duration * max_acceleration);
             let walk_boost = if let Some(climb_vectors) = &climb_vectors {
@@ -240,6 +298,21 @@ impl TnuaBasis for TnuaBuiltinWalk {
             };
             TnuaVelChange::acceleration(walk_acceleration)
         };
+        let walk_vel_change = match &ctx.proximity_sensor.output {
+            Some(sensor_output) if state.on_steep_slope => {
+                let uphill = -sensor_output
+                    .normal
+                    .adjust_precision()
+                    .reject_from(self.up.adjust_precision())
+                    .normalize_or_zero();
+                let reject_uphill = |vector: Vector3| vector - vector.dot(uphill).max(0.0) * uphill;
+                TnuaVelChange {
+                    acceleration: reject_uphill(walk_vel_change.acceleration),
+                    boost: reject_uphill(walk_vel_change.boost),
+                }
+            }
+            _ => walk_vel_change,
+        };
 
         state.vertical_velocity = if let Some(climb_vectors) = &climb_vectors {
             state.effective_velocity.dot(climb_vectors.direction)
@@ -248,16 +321,26 @@ impl TnuaBasis for TnuaBuiltinWalk {
             0.0
         };
 
+        let walkable_ground = ctx
+            .proximity_sensor
+            .output
+            .as_ref()
+            .filter(|_| !state.on_steep_slope);
+
         let upward_impulse: TnuaVelChange = 'upward_impulse: {
             for _ in 0..2 {
                 #[allow(clippy::unnecessary_cast)]
                 match &mut state.airborne_timer {
                     None => {
-                        if let Some(sensor_output) = &ctx.proximity_sensor.output {
+                        if let Some(sensor_output) = walkable_ground {
                             // not doing the jump calculation here
                             let spring_offset =
                                 self.float_height - sensor_output.proximity.adjust_precision();
                             state.standing_offset = -spring_offset;
+                            let spring_offset = spring_offset
+                                + self
+                                    .step_height(&ctx, sensor_output.proximity.adjust_precision())
+                                    .unwrap_or(0.0);
                             let boost = self.spring_force_boost(state, &ctx, spring_offset);
                             break 'upward_impulse TnuaVelChange::boost(
                                 boost * self.up.adjust_precision(),
@@ -267,17 +350,36 @@ impl TnuaBasis for TnuaBuiltinWalk {
                                 self.coyote_time as f32,
                                 TimerMode::Once,
                             ));
+                            state.airtime = 0.0;
+                            state.ground_contact_change = Some(TnuaGroundContactChange::TookOff {
+                                entity: previously_standing_on,
+                                upward_speed: state
+                                    .effective_velocity
+                                    .dot(self.up.adjust_precision()),
+                            });
                             continue;
                         }
                     }
                     Some(_) => {
-                        if let Some(sensor_output) = &ctx.proximity_sensor.output {
+                        if let Some(sensor_output) = walkable_ground {
                             if sensor_output.proximity.adjust_precision() <= self.float_height {
                                 state.airborne_timer = None;
+                                state.ground_contact_change =
+                                    Some(TnuaGroundContactChange::Landed {
+                                        entity: sensor_output.entity,
+                                        normal: sensor_output.normal,
+                                        impact_speed: -state
+                                            .effective_velocity
+                                            .dot(self.up.adjust_precision()),
+                                        airtime: state.airtime,
+                                    });
                                 continue;
                             }
                         }
-                        if state.vertical_velocity <= 0.0 {
+                        if state.on_steep_slope {
+                            // Let gravity slide the character down the slope.
+                            break 'upward_impulse TnuaVelChange::ZERO;
+                        } else if state.vertical_velocity <= 0.0 {
                             break 'upward_impulse TnuaVelChange::acceleration(
                                 -self.free_fall_extra_gravity * self.up.adjust_precision(),
                             );
@@ -297,49 +399,19 @@ impl TnuaBasis for TnuaBuiltinWalk {
             - impulse_to_offset;
         state.running_velocity = new_velocity.reject_from(self.up.adjust_precision());
 
-        // Tilt
-
-        let torque_to_fix_tilt = {
-            let tilted_up = ctx.tracker.rotation.mul_vec3(self.up.adjust_precision());
-
-            let rotation_required_to_fix_tilt =
-                Quaternion::from_rotation_arc(tilted_up, self.up.adjust_precision());
-
-            let desired_angvel = (rotation_required_to_fix_tilt.xyz() / ctx.frame_duration)
-                .clamp_length_max(self.tilt_offset_angvel);
-            let angular_velocity_diff = desired_angvel - ctx.tracker.angvel;
-            angular_velocity_diff.clamp_length_max(ctx.frame_duration * self.tilt_offset_angacl)
-        };
-
-        // Turning
-
-        let desired_angvel = if 0.0 < self.desired_forward.length_squared() {
-            let projection = ProjectionPlaneForRotation::from_up_using_default_forward(self.up);
-            let current_forward = ctx.tracker.rotation.mul_vec3(projection.forward);
-            let rotation_along_up_axis =
-                projection.rotation_to_set_forward(current_forward, self.desired_forward);
-            (rotation_along_up_axis / ctx.frame_duration)
-                .clamp(-self.turning_angvel, self.turning_angvel)
-        } else {
-            0.0
-        };
-
-        // NOTE: This is the regular axis system so we used the configured up.
-        let existing_angvel = ctx.tracker.angvel.dot(self.up.adjust_precision());
-
-        // This is the torque. Should it be clamped by an acceleration? From experimenting with
-        // this I think it's meaningless and only causes bugs.
-        let torque_to_turn = desired_angvel - existing_angvel;
-
-        let existing_turn_torque = torque_to_fix_tilt.dot(self.up.adjust_precision());
-        let torque_to_turn = torque_to_turn - existing_turn_torque;
-
-        motor.ang =
-            TnuaVelChange::boost(torque_to_fix_tilt + torque_to_turn * self.up.adjust_precision());
+        motor.ang = TnuaVelChange::boost(upright_and_turning_boost(
+            ctx.tracker,
+            ctx.frame_duration,
+            self.up,
+            self.desired_forward,
+            self.tilt_offset_angvel,
+            self.tilt_offset_angacl,
+            self.turning_angvel,
+        ));
     }
 
     fn proximity_sensor_cast_range(&self, _state: &Self::State) -> Float {
-        self.float_height + self.cling_distance
+        self.float_height + self.cling_distance + self.max_step_height
     }
 
     fn up_direction(&self, _state: &Self::State) -> Direction3d {
@@ -409,15 +481,34 @@ impl TnuaBuiltinWalk {
 
         ctx.frame_duration * (spring_force + gravity_compensation)
     }
+
+    /// The height, above the ground the character stands on, of the step the character walks
+    /// toward - if there is such a step and it is low enough to step up onto.
+    fn step_height(&self, ctx: &TnuaBasisContext, ground_proximity: Float) -> Option<Float> {
+        let up = self.up.adjust_precision();
+        if self.max_step_height <= 0.0 || self.step_normal.dot(up) <= 0.0 {
+            return None;
+        }
+        // The ground proximity is measured from the proximity sensor's cast origin, not from the
+        // character's center.
+        let proximity_cast_origin =
+            ctx.tracker.translation + ctx.tracker.rotation * ctx.proximity_sensor.cast_origin;
+        let to_step = self.step_point - proximity_cast_origin;
+        if self.desired_velocity.dot(to_step.reject_from(up)) <= 0.0 {
+            return None;
+        }
+        let step_height = to_step.dot(up) + ground_proximity;
+        (0.0 < step_height && step_height <= self.max_step_height).then_some(step_height)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct StandingOnState {
     entity: Entity,
     entity_linvel: Vector3,
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct TnuaBuiltinWalkState {
     airborne_timer: Option<Timer>,
     /// The current vertical distance of the character from the distance its supposed to float at.
@@ -432,6 +523,9 @@ pub struct TnuaBuiltinWalkState {
     /// ([`standing_on_entity`](Self::standing_on_entity) returns `Some`) then the
     /// `running_velocity` will be relative to the velocity of that entity.
     pub running_velocity: Vector3,
+    airtime: Float,
+    ground_contact_change: Option<TnuaGroundContactChange>,
+    on_steep_slope: bool,
 }
 
 impl TnuaBuiltinWalkState {
@@ -439,6 +533,55 @@ impl TnuaBuiltinWalkState {
     pub fn standing_on_entity(&self) -> Option<Entity> {
         Some(self.standing_on.as_ref()?.entity)
     }
+
+    /// Returns the change in the character's contact with the ground that happened this frame, if
+    /// there was one.
+    ///
+    /// This is only reported for the single frame where the change happened, so it needs to be
+    /// checked every frame.
+    pub fn ground_contact_change(&self) -> Option<&TnuaGroundContactChange> {
+        self.ground_contact_change.as_ref()
+    }
+
+    /// Check if the character is on ground that is steeper than
+    /// [`max_slope_angle`](TnuaBuiltinWalk::max_slope_angle).
+    ///
+    /// Such ground is not considered as ground, so once the coyote time is over the character is
+    /// considered airborne while sliding down the slope, and it lands once it reaches walkable
+    /// ground.
+    pub fn on_steep_slope(&self) -> bool {
+        self.on_steep_slope
+    }
+}
+
+/// A change in the character's contact with the ground, as reported by
+/// [`TnuaBuiltinWalkState::ground_contact_change`].
+#[derive(Debug, Clone)]
+pub enum TnuaGroundContactChange {
+    /// The character has lost its footing - either by jumping or by walking off a ledge.
+    ///
+    /// Note that this is reported when the ground is no longer detected, so if the character
+    /// walked off a ledge it is still in coyote time.
+    TookOff {
+        /// The entity the character was standing on before taking off.
+        entity: Option<Entity>,
+        /// The velocity along the [up](TnuaBuiltinWalk::up) axis when taking off.
+        upward_speed: Float,
+    },
+    /// The character is back on the ground.
+    Landed {
+        /// The entity the character has landed on.
+        entity: Entity,
+        /// The normal of the surface the character has landed on.
+        normal: Direction3d,
+        /// The speed, relative to the ground, in which the character was falling when it landed.
+        ///
+        /// This is measured along the [up](TnuaBuiltinWalk::up) axis, and is positive when the
+        /// character moves downward.
+        impact_speed: Float,
+        /// How long, in seconds, the character was in the air (including coyote time)
+        airtime: Float,
+    },
 }
 
 struct ClimbVectors {
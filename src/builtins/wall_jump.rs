@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+use super::TnuaBuiltinJumpState;
+
+/// An [action](TnuaAction) for jumping off a wall - up and away from it.
+///
+/// The upward part of the jump is done by [`jump`](Self::jump), so it can be shortened by no
+/// longer feeding the action (usually when the player releases the jump button) just like a
+/// regular jump. When the jump starts the character is also pushed away from the wall at
+/// [`push_speed`](Self::push_speed).
+///
+/// This action does not detect the wall by itself. The character entity needs a
+/// [`TnuaWallSensor`](crate::TnuaWallSensor), and the control system must feed its normal into
+/// [`wall_normal`](Self::wall_normal). Typically the control system will feed this action
+/// instead of [`TnuaBuiltinJump`] when the jump button is pressed while the character is in the
+/// air next to a wall.
+///
+/// Note that a walk basis with a high [air
+/// acceleration](crate::builtins::TnuaBuiltinWalk::air_acceleration) will quickly cancel the push
+/// away from the wall if the player keeps pushing toward it.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinWallJump {
+    /// The normal of the wall, pointing toward the character.
+    ///
+    /// This should be fed from the [`TnuaWallSensor`](crate::TnuaWallSensor). It is only used
+    /// when the jump starts, so there is no need to keep feeding it after the character leaves the
+    /// wall.
+    pub wall_normal: Vector3,
+
+    /// The speed in which the jump pushes the character away from the wall.
+    pub push_speed: Float,
+
+    /// The parameters of the upward part of the jump.
+    ///
+    /// The [`allow_in_air`](TnuaBuiltinJump::allow_in_air) of this jump is ignored, since a wall
+    /// jump always starts in the air.
+    pub jump: TnuaBuiltinJump,
+}
+
+impl Default for TnuaBuiltinWallJump {
+    fn default() -> Self {
+        Self {
+            wall_normal: Vector3::ZERO,
+            push_speed: 8.0,
+            jump: Default::default(),
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinWallJump {
+    const NAME: &'static str = "TnuaBuiltinWallJump";
+    type State = TnuaBuiltinWallJumpState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        _ctx: TnuaActionContext,
+        being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if self.wall_normal != Vector3::ZERO {
+            TnuaActionInitiationDirective::Allow
+        } else if (being_fed_for.elapsed().as_secs_f64() as Float) < self.jump.input_buffer_time {
+            TnuaActionInitiationDirective::Delay
+        } else {
+            TnuaActionInitiationDirective::Reject
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        let up = ctx.basis.up_direction().adjust_precision();
+
+        if lifecycle_status.just_started() {
+            *state = TnuaBuiltinWallJumpState {
+                push_direction: self.wall_normal.reject_from(up).normalize_or_zero(),
+                jump_state: Default::default(),
+            };
+        }
+
+        let jump = TnuaBuiltinJump {
+            allow_in_air: true,
+            ..self.jump.clone()
+        };
+        let effective_velocity = ctx.basis.effective_velocity();
+        let directive = jump.apply(&mut state.jump_state, ctx, lifecycle_status, motor);
+
+        if lifecycle_status.just_started() {
+            TnuaBuiltinJump::apply_push_velocity(
+                motor,
+                effective_velocity,
+                self.push_speed * state.push_direction,
+            );
+        }
+
+        directive
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinWallJumpState {
+    push_direction: Vector3,
+    jump_state: TnuaBuiltinJumpState,
+}
+
+impl TnuaBuiltinWallJumpState {
+    /// The direction away from the wall, orthogonal to the up direction.
+    pub fn push_direction(&self) -> Vector3 {
+        self.push_direction
+    }
+
+    /// The state of the upward part of the jump.
+    pub fn jump_state(&self) -> &TnuaBuiltinJumpState {
+        &self.jump_state
+    }
+}
@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+/// An [action](TnuaAction) for running along a wall.
+///
+/// While running, the character moves along the wall's tangent at [`speed`](Self::speed) and only
+/// a fraction of the gravity (set by [`gravity_factor`](Self::gravity_factor)) is applied to it.
+/// The direction along the tangent is decided by the character's velocity when the wall run
+/// starts, and the character must be moving along the wall at least at
+/// [`min_speed`](Self::min_speed) for the wall run to start.
+///
+/// This action does not detect the wall by itself. The character entity needs a
+/// [`TnuaWallSensor`](crate::TnuaWallSensor), and the control system must feed its normal into
+/// [`wall_normal`](Self::wall_normal) every frame. The wall run ends when:
+///
+/// * The wall is lost (`wall_normal` is fed as `Vector3::ZERO`).
+/// * The wall run lasts [`max_duration`](Self::max_duration).
+/// * The character lands on the ground.
+/// * The action is no longer fed.
+/// * Another action (usually [`TnuaBuiltinWallJump`](crate::builtins::TnuaBuiltinWallJump))
+///   cancels it.
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinWallRun {
+    /// The normal of the wall, pointing toward the character.
+    ///
+    /// This should be fed every frame from the [`TnuaWallSensor`](crate::TnuaWallSensor), or
+    /// `Vector3::ZERO` if the sensor does not detect a wall.
+    pub wall_normal: Vector3,
+
+    /// The speed to run along the wall in.
+    pub speed: Float,
+
+    /// The minimal speed along the wall the character needs to have for the wall run to start.
+    pub min_speed: Float,
+
+    /// The maximum acceleration for reaching [`speed`](Self::speed).
+    pub acceleration: Float,
+
+    /// The fraction of the gravity that still applies to the character while it runs along the
+    /// wall.
+    pub gravity_factor: Float,
+
+    /// The maximum duration, in seconds, of the wall run.
+    pub max_duration: Float,
+}
+
+impl Default for TnuaBuiltinWallRun {
+    fn default() -> Self {
+        Self {
+            wall_normal: Vector3::ZERO,
+            speed: 8.0,
+            min_speed: 3.0,
+            acceleration: 60.0,
+            gravity_factor: 0.2,
+            max_duration: 1.5,
+        }
+    }
+}
+
+impl TnuaBuiltinWallRun {
+    fn wall_tangent(&self, up: Vector3, toward: Vector3) -> Option<Vector3> {
+        let wall_normal = self.wall_normal.reject_from(up).try_normalize()?;
+        let tangent = up.cross(wall_normal);
+        if 0.0 <= tangent.dot(toward) {
+            Some(tangent)
+        } else {
+            Some(-tangent)
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinWallRun {
+    const NAME: &'static str = "TnuaBuiltinWallRun";
+    type State = TnuaBuiltinWallRunState;
+    const VIOLATES_COYOTE_TIME: bool = true;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if !ctx.basis.is_airborne() {
+            return TnuaActionInitiationDirective::Delay;
+        }
+        let up = ctx.basis.up_direction().adjust_precision();
+        let velocity = ctx.basis.effective_velocity();
+        match self.wall_tangent(up, velocity) {
+            Some(tangent) if self.min_speed <= velocity.dot(tangent) => {
+                TnuaActionInitiationDirective::Allow
+            }
+            _ => TnuaActionInitiationDirective::Delay,
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        let up = ctx.basis.up_direction().adjust_precision();
+        let velocity = ctx.basis.effective_velocity();
+
+        if lifecycle_status.just_started() {
+            *state = TnuaBuiltinWallRunState {
+                run_direction: self.wall_tangent(up, velocity).unwrap_or_default(),
+                ..Default::default()
+            };
+        } else {
+            state.elapsed += ctx.frame_duration;
+        }
+
+        if !lifecycle_status.is_active()
+            || !ctx.basis.is_airborne()
+            || self.max_duration <= state.elapsed
+        {
+            return TnuaActionLifecycleDirective::Finished;
+        }
+        let Some(run_direction) = self.wall_tangent(up, state.run_direction) else {
+            return TnuaActionLifecycleDirective::Finished;
+        };
+        state.run_direction = run_direction;
+        state.wall_normal = self.wall_normal;
+        state.side = if self.wall_normal.dot(run_direction.cross(up)) < 0.0 {
+            TnuaBuiltinWallRunSide::Right
+        } else {
+            TnuaBuiltinWallRunSide::Left
+        };
+
+        let wall_normal = self.wall_normal.reject_from(up).normalize_or_zero();
+        motor.lin.cancel_on_axis(run_direction);
+        let max_boost = self.acceleration * ctx.frame_duration;
+        motor.lin.boost +=
+            (self.speed - velocity.dot(run_direction)).clamp(-max_boost, max_boost) * run_direction;
+        // Don't drift away from the wall (or into it)
+        motor.lin.cancel_on_axis(wall_normal);
+        motor.lin.boost -= velocity.dot(wall_normal) * wall_normal;
+        motor.lin.cancel_on_axis(up);
+        motor.lin.acceleration -= (1.0 - self.gravity_factor) * ctx.tracker.gravity.dot(up) * up;
+        if lifecycle_status.just_started() {
+            // Stop the fall, so that the reduced gravity will be noticeable.
+            let upward_velocity = velocity.dot(up);
+            if upward_velocity < 0.0 {
+                motor.lin.boost -= upward_velocity * up;
+            }
+        }
+
+        TnuaActionLifecycleDirective::StillActive
+    }
+}
+
+/// The side of the character the wall is on, relative to the direction it runs in.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TnuaBuiltinWallRunSide {
+    #[default]
+    Left,
+    Right,
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinWallRunState {
+    wall_normal: Vector3,
+    run_direction: Vector3,
+    side: TnuaBuiltinWallRunSide,
+    elapsed: Float,
+}
+
+impl TnuaBuiltinWallRunState {
+    /// The normal of the wall the character runs on, as it was fed to the action.
+    pub fn wall_normal(&self) -> Vector3 {
+        self.wall_normal
+    }
+
+    /// The direction, along the wall, the character runs in.
+    pub fn run_direction(&self) -> Vector3 {
+        self.run_direction
+    }
+
+    /// The side of the character the wall is on.
+    ///
+    /// Useful for tilting the camera and for picking the wall run animation.
+    pub fn side(&self) -> TnuaBuiltinWallRunSide {
+        self.side
+    }
+
+    /// The time, in seconds, since the wall run started.
+    pub fn elapsed(&self) -> Float {
+        self.elapsed
+    }
+}
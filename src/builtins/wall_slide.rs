@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, Float, Vector3};
+
+use crate::{
+    prelude::*, TnuaActionContext, TnuaActionInitiationDirective, TnuaActionLifecycleDirective,
+    TnuaActionLifecycleStatus, TnuaMotor,
+};
+
+/// An [action](TnuaAction) for sliding down a wall, capping the fall speed while the character
+/// presses into it.
+///
+/// This action does not detect the wall by itself. The character entity needs a
+/// [`TnuaWallSensor`](crate::TnuaWallSensor) pointed in the direction the player pushes toward,
+/// and the control system must feed its normal into [`wall_normal`](Self::wall_normal). The
+/// action should only be fed while the player pushes into the wall - once it is no longer fed the
+/// character will fall normally.
+///
+/// The action will wait for the character to be in the air, so it can be fed while the character
+/// is still on the ground (e.g. right before jumping toward the wall).
+#[derive(Clone, Reflect)]
+#[reflect(Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct TnuaBuiltinWallSlide {
+    /// The normal of the wall, pointing toward the character.
+    ///
+    /// This should be fed every frame from the [`TnuaWallSensor`](crate::TnuaWallSensor), or
+    /// `Vector3::ZERO` if the sensor does not detect a wall. The action ends when there is no
+    /// wall.
+    pub wall_normal: Vector3,
+
+    /// The maximum speed the character will fall in while sliding down the wall.
+    pub max_fall_speed: Float,
+
+    /// The maximum acceleration for slowing the character down to
+    /// [`max_fall_speed`](Self::max_fall_speed) when it hits the wall while falling faster.
+    pub deceleration: Float,
+}
+
+impl Default for TnuaBuiltinWallSlide {
+    fn default() -> Self {
+        Self {
+            wall_normal: Vector3::ZERO,
+            max_fall_speed: 2.0,
+            deceleration: 60.0,
+        }
+    }
+}
+
+impl TnuaAction for TnuaBuiltinWallSlide {
+    const NAME: &'static str = "TnuaBuiltinWallSlide";
+    type State = TnuaBuiltinWallSlideState;
+    const VIOLATES_COYOTE_TIME: bool = false;
+
+    fn initiation_decision(
+        &self,
+        ctx: TnuaActionContext,
+        _being_fed_for: &bevy::time::Stopwatch,
+    ) -> TnuaActionInitiationDirective {
+        if self.wall_normal != Vector3::ZERO && ctx.basis.is_airborne() {
+            TnuaActionInitiationDirective::Allow
+        } else {
+            TnuaActionInitiationDirective::Delay
+        }
+    }
+
+    fn apply(
+        &self,
+        state: &mut Self::State,
+        ctx: TnuaActionContext,
+        lifecycle_status: TnuaActionLifecycleStatus,
+        motor: &mut TnuaMotor,
+    ) -> TnuaActionLifecycleDirective {
+        if !lifecycle_status.is_active()
+            || self.wall_normal == Vector3::ZERO
+            || !ctx.basis.is_airborne()
+        {
+            return TnuaActionLifecycleDirective::Finished;
+        }
+        state.wall_normal = self.wall_normal;
+
+        let up = ctx.basis.up_direction().adjust_precision();
+        let upward_velocity = ctx.basis.effective_velocity().dot(up);
+        if upward_velocity <= -self.max_fall_speed {
+            motor.lin.cancel_on_axis(up);
+            // Cancel the gravity, so that it will not make the character fall faster
+            motor.lin.acceleration -= ctx.tracker.gravity.dot(up) * up;
+            motor.lin.boost += (-self.max_fall_speed - upward_velocity)
+                .min(self.deceleration * ctx.frame_duration)
+                * up;
+        }
+
+        TnuaActionLifecycleDirective::StillActive
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct TnuaBuiltinWallSlideState {
+    wall_normal: Vector3,
+}
+
+impl TnuaBuiltinWallSlideState {
+    /// The normal of the wall the character slides on, as it was fed to the action.
+    ///
+    /// Useful for turning the character's model away from the wall while it slides.
+    pub fn wall_normal(&self) -> Vector3 {
+        self.wall_normal
+    }
+}
@@ -8,8 +8,12 @@
 //! although less flexible way.
 mod air_actions_tracking;
 mod crouch_enforcer;
+#[cfg(feature = "movement_profile")]
+mod movement_profile;
 mod simple_fall_through_platforms;
 
 pub use air_actions_tracking::*;
 pub use crouch_enforcer::*;
+#[cfg(feature = "movement_profile")]
+pub use movement_profile::*;
 pub use simple_fall_through_platforms::*;
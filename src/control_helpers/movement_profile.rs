@@ -0,0 +1,124 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, AsyncReadExt, LoadContext};
+use bevy::prelude::*;
+use bevy::utils::BoxedFuture;
+
+use crate::builtins::{TnuaBuiltinCrouch, TnuaBuiltinDash, TnuaBuiltinJump, TnuaBuiltinWalk};
+
+/// Add this plugin to load [`TnuaMovementProfile`] assets from `.tnua.ron` files.
+///
+/// Requires the `movement_profile` feature, and Bevy's `AssetPlugin`. To hot-reload the profiles
+/// while tweaking them, enable Bevy's `file_watcher` feature.
+pub struct TnuaMovementProfilePlugin;
+
+impl Plugin for TnuaMovementProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<TnuaMovementProfile>();
+        app.init_asset_loader::<TnuaMovementProfileLoader>();
+    }
+}
+
+/// An asset that bundles the configuration of the common builtin basis and actions.
+///
+/// The profile is loaded from a RON file, where every field (and every field of the builtins
+/// inside it) can be omitted to use its default value:
+///
+/// ```ron
+/// (
+///     walk: (
+///         float_height: 2.0,
+///         acceleration: 60.0,
+///     ),
+///     jump: (
+///         height: 4.0,
+///     ),
+///     dash: (
+///         speed: 100.0,
+///     ),
+///     crouch: (
+///         float_offset: -0.9,
+///     ),
+/// )
+/// ```
+///
+/// The control system should clone the builtins from the profile every frame, and fill in the
+/// user input fields - this way, changes to the profile (e.g. when it is hot-reloaded) will take
+/// effect immediately:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_tnua::prelude::*;
+/// # use bevy_tnua::control_helpers::TnuaMovementProfile;
+/// # use bevy_tnua::math::Vector3;
+/// # #[derive(Component)]
+/// # struct PlayerInputComponent;
+/// # impl PlayerInputComponent {
+/// # fn direction_vector(&self) -> Vector3 { Vector3::ZERO }
+/// # fn jump_pressed(&self) -> bool { false }
+/// # }
+/// fn player_control_system(
+///     mut query: Query<(
+///         &mut TnuaController,
+///         &Handle<TnuaMovementProfile>,
+///         &PlayerInputComponent,
+///     )>,
+///     profiles: Res<Assets<TnuaMovementProfile>>,
+/// ) {
+///     for (mut controller, profile, player_input) in query.iter_mut() {
+///         let Some(profile) = profiles.get(profile) else {
+///             continue;
+///         };
+///         controller.basis(TnuaBuiltinWalk {
+///             desired_velocity: player_input.direction_vector() * 10.0,
+///             desired_forward: player_input.direction_vector(),
+///             ..profile.walk.clone()
+///         });
+///         if player_input.jump_pressed() {
+///             controller.action(profile.jump.clone());
+///         }
+///     }
+/// }
+/// ```
+#[derive(Asset, TypePath, Clone, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct TnuaMovementProfile {
+    pub walk: TnuaBuiltinWalk,
+    pub jump: TnuaBuiltinJump,
+    pub dash: TnuaBuiltinDash,
+    pub crouch: TnuaBuiltinCrouch,
+}
+
+/// Loads [`TnuaMovementProfile`] assets. Registered by [`TnuaMovementProfilePlugin`].
+#[derive(Default)]
+pub struct TnuaMovementProfileLoader;
+
+#[derive(thiserror::Error, Debug)]
+pub enum TnuaMovementProfileLoaderError {
+    #[error("Could not read the movement profile: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse the movement profile: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for TnuaMovementProfileLoader {
+    type Asset = TnuaMovementProfile;
+    type Settings = ();
+    type Error = TnuaMovementProfileLoaderError;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            Ok(ron::de::from_bytes(&bytes)?)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tnua.ron"]
+    }
+}
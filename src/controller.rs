@@ -1,3 +1,4 @@
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
 use bevy::time::Stopwatch;
 use bevy::utils::{Entry, HashMap};
@@ -8,21 +9,52 @@ use crate::basis_action_traits::{
     TnuaActionInitiationDirective, TnuaActionLifecycleDirective, TnuaActionLifecycleStatus,
     TnuaBasisContext,
 };
+use crate::builtins::{
+    TnuaBuiltinChargeJump, TnuaBuiltinClimb, TnuaBuiltinCrouch, TnuaBuiltinDash,
+    TnuaBuiltinDirectionalJump, TnuaBuiltinFreeFlight, TnuaBuiltinGlide, TnuaBuiltinGroundPound,
+    TnuaBuiltinJump, TnuaBuiltinKnockback, TnuaBuiltinLedgeGrab, TnuaBuiltinMantle,
+    TnuaBuiltinSlide, TnuaBuiltinSwim, TnuaBuiltinSwing, TnuaBuiltinWalk, TnuaBuiltinWallJump,
+    TnuaBuiltinWallRun, TnuaBuiltinWallSlide, TnuaClimbable,
+};
 use crate::{
-    TnuaBasis, TnuaMotor, TnuaPipelineStages, TnuaProximitySensor, TnuaRigidBodyTracker,
-    TnuaSystemSet, TnuaToggle, TnuaUserControlsSystemSet,
+    TnuaBasis, TnuaGhostPlatform, TnuaGhostSensor, TnuaLedgeSensor, TnuaMotor, TnuaPipelineStages,
+    TnuaProximitySensor, TnuaRigidBodyTracker, TnuaStepSensor, TnuaSurface, TnuaSystemSet,
+    TnuaToggle, TnuaUserControlsSystemSet, TnuaWallSensor, TnuaWaterSensor, TnuaWaterVolume,
 };
 
 /// The main for supporting Tnua character controller.
 ///
 /// Will not work without a physics backend plugin (like `TnuaRapier2dPlugin` or
 /// `TnuaRapier3dPlugin`)
-pub struct TnuaControllerPlugin;
+///
+/// Make sure the schedule for this plugin, the physics backend plugin, and the physics backend
+/// itself are all using the same timestep.
+pub struct TnuaControllerPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl TnuaControllerPlugin {
+    /// Create the plugin, running the Tnua pipeline in the given schedule.
+    ///
+    /// When running in a fixed timestep schedule (like `FixedUpdate`), the frame duration will be
+    /// taken from `Time<Fixed>`.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
+impl Default for TnuaControllerPlugin {
+    fn default() -> Self {
+        Self::new(Update)
+    }
+}
 
 impl Plugin for TnuaControllerPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(
-            Update,
+            self.schedule,
             (
                 TnuaPipelineStages::Sensors,
                 TnuaPipelineStages::SubservientSensors,
@@ -34,9 +66,46 @@ impl Plugin for TnuaControllerPlugin {
                 .in_set(TnuaSystemSet),
         );
         app.add_systems(
-            Update,
+            self.schedule,
             apply_controller_system.in_set(TnuaPipelineStages::Logic),
         );
+        app.register_type::<TnuaController>();
+        app.register_type::<TnuaMotor>();
+        app.register_type::<TnuaRigidBodyTracker>();
+        app.register_type::<TnuaProximitySensor>();
+        app.register_type::<TnuaGhostSensor>();
+        app.register_type::<TnuaGhostPlatform>();
+        app.register_type::<TnuaToggle>();
+        app.register_type::<TnuaWaterVolume>();
+        app.register_type::<TnuaWaterSensor>();
+        app.register_type::<TnuaWallSensor>();
+        app.register_type::<TnuaLedgeSensor>();
+        app.register_type::<TnuaStepSensor>();
+        app.register_type::<TnuaBuiltinWalk>();
+        app.register_type::<TnuaBuiltinJump>();
+        app.register_type::<TnuaBuiltinDash>();
+        app.register_type::<TnuaBuiltinCrouch>();
+        app.register_type::<TnuaBuiltinSwim>();
+        app.register_type::<TnuaBuiltinFreeFlight>();
+        app.register_type::<TnuaBuiltinClimb>();
+        app.register_type::<TnuaClimbable>();
+        app.register_type::<TnuaSurface>();
+        app.register_type::<TnuaBuiltinWallSlide>();
+        app.register_type::<TnuaBuiltinWallJump>();
+        app.register_type::<TnuaBuiltinWallRun>();
+        app.register_type::<TnuaBuiltinLedgeGrab>();
+        app.register_type::<TnuaBuiltinMantle>();
+        app.register_type::<TnuaBuiltinSlide>();
+        app.register_type::<TnuaBuiltinGlide>();
+        app.register_type::<TnuaBuiltinGroundPound>();
+        app.register_type::<TnuaBuiltinKnockback>();
+        app.register_type::<TnuaBuiltinSwing>();
+        app.register_type::<TnuaBuiltinChargeJump>();
+        app.register_type::<TnuaBuiltinDirectionalJump>();
+        app.add_event::<TnuaActionStarted>();
+        app.add_event::<TnuaActionEnded>();
+        app.add_event::<TnuaActionCancelled>();
+        app.add_event::<TnuaActionRejected>();
     }
 }
 
@@ -52,6 +121,7 @@ pub struct TnuaControllerBundle {
     pub proximity_sensor: TnuaProximitySensor,
 }
 
+#[derive(Clone)]
 struct FedEntry {
     fed_this_frame: bool,
     rescheduled_in: Option<Timer>,
@@ -79,11 +149,16 @@ struct FedEntry {
 ///   `TnuaAction`](crate::TnuaAction#implementors) for more information.
 ///
 /// Without [`TnuaControllerPlugin`] this component will not do anything.
-#[derive(Component, Default)]
+#[derive(Component, Default, Reflect)]
+#[reflect(Component, Default)]
 pub struct TnuaController {
+    #[reflect(ignore)]
     current_basis: Option<(&'static str, Box<dyn DynamicBasis>)>,
+    #[reflect(ignore)]
     actions_being_fed: HashMap<&'static str, FedEntry>,
+    #[reflect(ignore)]
     current_action: Option<(&'static str, Box<dyn DynamicAction>)>,
+    #[reflect(ignore)]
     contender_action: Option<(&'static str, Box<dyn DynamicAction>, Stopwatch)>,
     action_flow_status: TnuaActionFlowStatus,
 }
@@ -179,6 +254,20 @@ impl TnuaController {
                         // different action is running - will not override because button was
                         // already pressed.
                     }
+                } else if let Some((_, contender_action, _)) = self
+                    .contender_action
+                    .as_mut()
+                    .filter(|(contender_name, ..)| *contender_name == name)
+                {
+                    // the action is still waiting to be initiated - update its input so that the
+                    // initiation decision will be made with fresh data.
+                    let Some(contender_action) = contender_action
+                        .as_mut_any()
+                        .downcast_mut::<BoxableAction<A>>()
+                    else {
+                        panic!("Multiple action types registered with same name {name:?}");
+                    };
+                    contender_action.input = action;
                 } else if self.contender_action.is_none()
                     && entry
                         .get()
@@ -262,6 +351,9 @@ impl TnuaController {
     ///   after stopping or cancelled into itself.
     /// * `action_flow_status` shows an [`ActionEnded`](TnuaActionFlowStatus::ActionEnded) when the
     ///   action is no longer fed, even if the action is still active (termination sequence)
+    ///
+    /// Systems that only need to react to changes in the flow can read the [`TnuaActionStarted`],
+    /// [`TnuaActionEnded`], [`TnuaActionCancelled`] and [`TnuaActionRejected`] events instead.
     pub fn action_flow_status(&self) -> &TnuaActionFlowStatus {
         &self.action_flow_status
     }
@@ -277,14 +369,60 @@ impl TnuaController {
             None => Err(TnuaControllerHasNoBasis),
         }
     }
+
+    /// Take a copy of the entire internal state of the controller, so that it can be restored
+    /// later with [`restore`](Self::restore).
+    ///
+    /// This is meant for rollback networking. Note that the snapshot only covers the
+    /// `TnuaController` component - the other components that affect the simulation (like
+    /// [`TnuaMotor`] and [`TnuaProximitySensor`]) should be cloned and restored alongside it.
+    pub fn snapshot(&self) -> TnuaControllerSnapshot {
+        TnuaControllerSnapshot(self.clone())
+    }
+
+    /// Restore the controller to the state it had when the snapshot was taken.
+    ///
+    /// The same snapshot can be restored multiple times.
+    pub fn restore(&mut self, snapshot: &TnuaControllerSnapshot) {
+        *self = snapshot.0.clone();
+    }
 }
 
+impl Clone for TnuaController {
+    fn clone(&self) -> Self {
+        Self {
+            current_basis: self
+                .current_basis
+                .as_ref()
+                .map(|(name, basis)| (*name, basis.boxed_clone())),
+            actions_being_fed: self.actions_being_fed.clone(),
+            current_action: self
+                .current_action
+                .as_ref()
+                .map(|(name, action)| (*name, action.boxed_clone())),
+            contender_action: self.contender_action.as_ref().map(
+                |(name, action, being_fed_for)| {
+                    (*name, action.boxed_clone(), being_fed_for.clone())
+                },
+            ),
+            action_flow_status: self.action_flow_status.clone(),
+        }
+    }
+}
+
+/// The result of [`TnuaController::snapshot()`].
+///
+/// Holds the basis and the action (both their inputs and their states), the actions that are
+/// being fed and the action flow status. Use [`TnuaController::restore()`] to apply it.
+#[derive(Clone)]
+pub struct TnuaControllerSnapshot(TnuaController);
+
 #[derive(thiserror::Error, Debug)]
 #[error("The Tnua controller does not have any basis set")]
 pub struct TnuaControllerHasNoBasis;
 
 /// The result of [`TnuaController::action_flow_status()`].
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Reflect)]
 pub enum TnuaActionFlowStatus {
     /// No action is going on.
     #[default]
@@ -342,22 +480,77 @@ impl TnuaActionFlowStatus {
     }
 }
 
+/// Sent when an action starts - either when no action was running before, or when the action
+/// cancels another action (in which case [`TnuaActionCancelled`] is sent as well)
+#[derive(Event, Debug, Clone)]
+pub struct TnuaActionStarted {
+    /// The character entity that performs the action.
+    pub entity: Entity,
+    /// The name of the action, as fed to the [`TnuaController`].
+    pub action_name: &'static str,
+}
+
+/// Sent when an action finishes, including its termination sequence.
+///
+/// Unlike [`TnuaActionFlowStatus::ActionEnded`], this is not sent when the action stops being fed
+/// while it is still active - e.g. a [`TnuaBuiltinJump`](crate::builtins::TnuaBuiltinJump) is
+/// only ended once the character lands, even if the player released the jump button before that.
+/// This event is not sent when the action gets cancelled into another action -
+/// [`TnuaActionCancelled`] is sent instead.
+#[derive(Event, Debug, Clone)]
+pub struct TnuaActionEnded {
+    /// The character entity that performed the action.
+    pub entity: Entity,
+    /// The name of the action, as fed to the [`TnuaController`].
+    pub action_name: &'static str,
+}
+
+/// Sent when an action gets cancelled into another action.
+#[derive(Event, Debug, Clone)]
+pub struct TnuaActionCancelled {
+    /// The character entity that performs the actions.
+    pub entity: Entity,
+    /// The name of the action that got cancelled.
+    pub old: &'static str,
+    /// The name of the action that replaced it.
+    pub new: &'static str,
+}
+
+/// Sent when an action was fed, but its [`initiation_decision`](TnuaAction::initiation_decision)
+/// rejected it.
+#[derive(Event, Debug, Clone)]
+pub struct TnuaActionRejected {
+    /// The character entity the action was fed to.
+    pub entity: Entity,
+    /// The name of the action, as fed to the [`TnuaController`].
+    pub action_name: &'static str,
+}
+
+// NOTE: `Time` (and not `Time<Virtual>`) is used on purpose - Bevy sets it to `Time<Fixed>` when
+// running inside a fixed timestep schedule and to `Time<Virtual>` otherwise, so this system will
+// get the correct frame duration regardless of which schedule it runs in.
 #[allow(clippy::type_complexity)]
 fn apply_controller_system(
     time: Res<Time>,
     mut query: Query<(
+        Entity,
         &mut TnuaController,
         &TnuaRigidBodyTracker,
         &mut TnuaProximitySensor,
         &mut TnuaMotor,
         Option<&TnuaToggle>,
     )>,
+    surfaces_query: Query<&TnuaSurface>,
+    mut action_started_writer: EventWriter<TnuaActionStarted>,
+    mut action_ended_writer: EventWriter<TnuaActionEnded>,
+    mut action_cancelled_writer: EventWriter<TnuaActionCancelled>,
+    mut action_rejected_writer: EventWriter<TnuaActionRejected>,
 ) {
     let frame_duration = time.delta().as_secs_f64() as Float;
     if frame_duration == 0.0 {
         return;
     }
-    for (mut controller, tracker, mut sensor, mut motor, tnua_toggle) in query.iter_mut() {
+    for (entity, mut controller, tracker, mut sensor, mut motor, tnua_toggle) in query.iter_mut() {
         match tnua_toggle.copied().unwrap_or_default() {
             TnuaToggle::Disabled => continue,
             TnuaToggle::SenseOnly => {}
@@ -379,6 +572,14 @@ fn apply_controller_system(
                 controller.action_flow_status = TnuaActionFlowStatus::ActionOngoing(action_name);
             }
         }
+        // Only set when the action is really over - not when it merely stops being fed and
+        // enters its termination sequence.
+        let mut ended_action = None;
+
+        let surface = sensor
+            .output
+            .as_ref()
+            .and_then(|sensor_output| surfaces_query.get(sensor_output.entity).ok());
 
         if let Some((_, basis)) = controller.current_basis.as_mut() {
             let basis = basis.as_mut();
@@ -387,6 +588,7 @@ fn apply_controller_system(
                     frame_duration,
                     tracker,
                     proximity_sensor: sensor.as_ref(),
+                    surface,
                 },
                 motor.as_mut(),
             );
@@ -403,6 +605,7 @@ fn apply_controller_system(
                         frame_duration,
                         tracker,
                         proximity_sensor,
+                        surface,
                         basis,
                     },
                     being_fed_for,
@@ -410,7 +613,12 @@ fn apply_controller_system(
                 being_fed_for.tick(time.delta());
                 match initiation_decision {
                     TnuaActionInitiationDirective::Reject => {
-                        controller.contender_action = None;
+                        if let Some((contender_name, ..)) = controller.contender_action.take() {
+                            action_rejected_writer.send(TnuaActionRejected {
+                                entity,
+                                action_name: contender_name,
+                            });
+                        }
                         false
                     }
                     TnuaActionInitiationDirective::Delay => false,
@@ -439,6 +647,7 @@ fn apply_controller_system(
                         frame_duration,
                         tracker,
                         proximity_sensor,
+                        surface,
                         basis,
                     },
                     lifecycle_status,
@@ -485,6 +694,7 @@ fn apply_controller_system(
                                     frame_duration,
                                     tracker,
                                     proximity_sensor,
+                                    surface,
                                     basis,
                                 },
                                 TnuaActionLifecycleStatus::CancelledFrom,
@@ -505,6 +715,9 @@ fn apply_controller_system(
                                                 new: contender_name,
                                             };
                                     } else {
+                                        // The old action was in its termination sequence, so it
+                                        // did not get cancelled.
+                                        ended_action = Some(*name);
                                         controller.action_flow_status =
                                             TnuaActionFlowStatus::ActionStarted(contender_name);
                                     }
@@ -518,6 +731,7 @@ fn apply_controller_system(
                                         controller.action_flow_status =
                                             TnuaActionFlowStatus::ActionEnded(name);
                                     }
+                                    ended_action = Some(*name);
                                     None
                                 }
                                 TnuaActionLifecycleDirective::Reschedule { after_seconds } => {
@@ -528,6 +742,7 @@ fn apply_controller_system(
                                         controller.action_flow_status =
                                             TnuaActionFlowStatus::ActionEnded(name);
                                     }
+                                    ended_action = Some(*name);
                                     reschedule_action(
                                         &mut controller.actions_being_fed,
                                         after_seconds,
@@ -537,6 +752,7 @@ fn apply_controller_system(
                             }
                         } else {
                             controller.action_flow_status = TnuaActionFlowStatus::ActionEnded(name);
+                            ended_action = Some(*name);
                             None
                         };
                     }
@@ -551,6 +767,7 @@ fn apply_controller_system(
                         frame_duration,
                         tracker,
                         proximity_sensor,
+                        surface,
                         basis,
                     },
                     TnuaActionLifecycleStatus::Initiated,
@@ -573,6 +790,31 @@ fn apply_controller_system(
             sensor.cast_range = sensor_cast_range_for_basis.max(sensor_case_range_for_action);
         }
 
+        if let Some(action_name) = ended_action {
+            action_ended_writer.send(TnuaActionEnded {
+                entity,
+                action_name,
+            });
+        }
+        match controller.action_flow_status {
+            TnuaActionFlowStatus::NoAction
+            | TnuaActionFlowStatus::ActionOngoing(_)
+            | TnuaActionFlowStatus::ActionEnded(_) => {}
+            TnuaActionFlowStatus::ActionStarted(action_name) => {
+                action_started_writer.send(TnuaActionStarted {
+                    entity,
+                    action_name,
+                });
+            }
+            TnuaActionFlowStatus::Cancelled { old, new } => {
+                action_cancelled_writer.send(TnuaActionCancelled { entity, old, new });
+                action_started_writer.send(TnuaActionStarted {
+                    entity,
+                    action_name: new,
+                });
+            }
+        }
+
         // Cycle actions_being_fed
         controller.actions_being_fed.retain(|_, fed_entry| {
             if fed_entry.fed_this_frame {
@@ -0,0 +1,163 @@
+//! Visualization of Tnua's sensors and motors, for debugging.
+//!
+//! Requires the `debug_gizmos` feature. Add [`TnuaDebugGizmosPlugin`] to the app to draw:
+//!
+//! * The ray of every [`TnuaProximitySensor`] - yellow up to where it hits, and grey for the rest
+//!   of its range - and a blue arrow for the normal of the hit.
+//! * The hits of every [`TnuaGhostSensor`], in purple.
+//! * The linear part of every [`TnuaMotor`] - the boost in red and the acceleration in orange.
+//! * For characters that use [`TnuaBuiltinWalk`] - the desired velocity in cyan, the desired
+//!   forward in white, and two green circles along the sensor's ray that mark the float height
+//!   and the end of the cling distance.
+//!
+//! Only the components from the physics integration layer are used, so this works with all
+//! physics backends. This also means that when the physics backend casts a shape instead of a ray
+//! the gizmos will only show the ray along the center of that shape.
+//!
+//! The drawing can be configured (and disabled) through the [`TnuaDebugGizmos`] gizmo config
+//! group.
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use bevy_tnua_physics_integration_layer::math::{AdjustPrecision, AsF32, Vector3};
+
+use crate::builtins::TnuaBuiltinWalk;
+use crate::controller::TnuaController;
+use crate::{TnuaGhostSensor, TnuaMotor, TnuaProximitySensor};
+
+/// Draws debug gizmos for Tnua's sensors and motors.
+///
+/// The gizmos are drawn in `PostUpdate` and only read the components, so this plugin can be added
+/// regardless of the schedule the Tnua pipeline runs in.
+pub struct TnuaDebugGizmosPlugin;
+
+impl Plugin for TnuaDebugGizmosPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_gizmo_group::<TnuaDebugGizmos>();
+        app.add_systems(
+            PostUpdate,
+            (
+                draw_proximity_sensors_system,
+                draw_ghost_sensors_system,
+                draw_motors_system,
+                draw_walk_basis_system,
+            )
+                .after(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+/// Gizmo config group for the gizmos drawn by [`TnuaDebugGizmosPlugin`].
+///
+/// Use `GizmoConfigStore` to change the general configuration (e.g. to disable the drawing) and
+/// to change these fields.
+#[derive(GizmoConfigGroup, Reflect)]
+pub struct TnuaDebugGizmos {
+    /// The motor's boost is drawn multiplied by this factor.
+    pub boost_scale: f32,
+    /// The motor's acceleration is drawn multiplied by this factor.
+    ///
+    /// Accelerations are usually much bigger than the distances in the scene, so this defaults to
+    /// a small value.
+    pub acceleration_scale: f32,
+    /// The radius of the circles that mark the float height and cling distance.
+    pub float_marker_radius: f32,
+}
+
+impl Default for TnuaDebugGizmos {
+    fn default() -> Self {
+        Self {
+            boost_scale: 1.0,
+            acceleration_scale: 0.05,
+            float_marker_radius: 0.3,
+        }
+    }
+}
+
+/// The cast origin and cast direction of the sensor, in world coordinates.
+fn sensor_ray(transform: &GlobalTransform, sensor: &TnuaProximitySensor) -> (Vector3, Vector3) {
+    let cast_origin = transform
+        .transform_point(sensor.cast_origin.f32())
+        .adjust_precision();
+    let (_, rotation, _) = transform.to_scale_rotation_translation();
+    let cast_direction = (rotation * *sensor.cast_direction).adjust_precision();
+    (cast_origin, cast_direction)
+}
+
+fn draw_proximity_sensors_system(
+    query: Query<(&GlobalTransform, &TnuaProximitySensor)>,
+    mut gizmos: Gizmos<TnuaDebugGizmos>,
+) {
+    for (transform, sensor) in query.iter() {
+        let (cast_origin, cast_direction) = sensor_ray(transform, sensor);
+        let cast_end = (cast_origin + sensor.cast_range * cast_direction).f32();
+        let cast_origin_f32 = cast_origin.f32();
+        if let Some(output) = &sensor.output {
+            let hit_point = (cast_origin + output.proximity * cast_direction).f32();
+            gizmos.line(cast_origin_f32, hit_point, Color::YELLOW);
+            gizmos.line(hit_point, cast_end, Color::GRAY);
+            gizmos.arrow(hit_point, hit_point + *output.normal, Color::BLUE);
+        } else {
+            gizmos.line(cast_origin_f32, cast_end, Color::GRAY);
+        }
+    }
+}
+
+fn draw_ghost_sensors_system(
+    query: Query<(&GlobalTransform, &TnuaProximitySensor, &TnuaGhostSensor)>,
+    mut gizmos: Gizmos<TnuaDebugGizmos>,
+) {
+    for (transform, sensor, ghost_sensor) in query.iter() {
+        let (cast_origin, cast_direction) = sensor_ray(transform, sensor);
+        for output in ghost_sensor.iter() {
+            let hit_point = (cast_origin + output.proximity * cast_direction).f32();
+            gizmos.arrow(hit_point, hit_point + *output.normal, Color::PURPLE);
+        }
+    }
+}
+
+fn draw_motors_system(
+    query: Query<(&GlobalTransform, &TnuaMotor)>,
+    config_store: Res<GizmoConfigStore>,
+    mut gizmos: Gizmos<TnuaDebugGizmos>,
+) {
+    let (_, config) = config_store.config::<TnuaDebugGizmos>();
+    for (transform, motor) in query.iter() {
+        let center = transform.translation();
+        gizmos.arrow(
+            center,
+            center + config.boost_scale * motor.lin.boost.f32(),
+            Color::RED,
+        );
+        gizmos.arrow(
+            center,
+            center + config.acceleration_scale * motor.lin.acceleration.f32(),
+            Color::ORANGE,
+        );
+    }
+}
+
+fn draw_walk_basis_system(
+    query: Query<(&GlobalTransform, &TnuaController, &TnuaProximitySensor)>,
+    config_store: Res<GizmoConfigStore>,
+    mut gizmos: Gizmos<TnuaDebugGizmos>,
+) {
+    let (_, config) = config_store.config::<TnuaDebugGizmos>();
+    for (transform, controller, sensor) in query.iter() {
+        let Some((walk, _)) = controller.concrete_basis::<TnuaBuiltinWalk>() else {
+            continue;
+        };
+        let center = transform.translation();
+        gizmos.arrow(center, center + walk.desired_velocity.f32(), Color::CYAN);
+        gizmos.arrow(center, center + walk.desired_forward.f32(), Color::WHITE);
+
+        let (cast_origin, cast_direction) = sensor_ray(transform, sensor);
+        for distance in [walk.float_height, walk.float_height + walk.cling_distance] {
+            gizmos.circle(
+                (cast_origin + distance * cast_direction).f32(),
+                walk.up,
+                config.float_marker_radius,
+                Color::GREEN,
+            );
+        }
+    }
+}
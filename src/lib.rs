@@ -11,6 +11,8 @@
 //! * For Rapier 3D, add the [bevy-tnua-rapier3d](https://crates.io/crates/bevy-tnua-rapier3d) crate.
 //! * For XPBD 2D, add the [bevy-tnua-xpbd2d](https://crates.io/crates/bevy-tnua-xpbd2d) crate.
 //! * For XPBD 3D, add the [bevy-tnua-xpbd3d](https://crates.io/crates/bevy-tnua-xpbd3d) crate.
+//! * For headless tests that don't need a real physics engine, add the
+//!   [bevy-tnua-mock](https://crates.io/crates/bevy-tnua-mock) crate.
 //! * Third party integration crates. Such crates should depend on
 //!   [bevy-tnua-physics-integration-layer](https://crates.io/crates/bevy-tnua-physics-integration-layer)
 //!   and not the main bevy-tnua crate.
@@ -109,6 +111,18 @@
 //! but essentially the _basis_ controls the general movement and the _action_ is something
 //! special (jump, dash, crouch, etc.)
 //!
+//! ## Additional Sensors
+//!
+//! The only sensor the controller reads by itself is the [`TnuaProximitySensor`], which is part
+//! of the [`TnuaControllerBundle`](prelude::TnuaControllerBundle). Some builtins need information
+//! from other sensors - [`TnuaWaterSensor`], [`TnuaWallSensor`], [`TnuaLedgeSensor`] and
+//! [`TnuaStepSensor`]. These sensors are not added by default, and while the physics backend
+//! updates them, Tnua does not read them. The control system is responsible for pointing them
+//! (where relevant) and for feeding their output into the fields of the bases and actions that
+//! need it (e.g. [`TnuaBuiltinSwim::water_depth`](builtins::TnuaBuiltinSwim::water_depth) or
+//! [`TnuaBuiltinWalk::step_point`](builtins::TnuaBuiltinWalk::step_point)). This lets the game
+//! decide what the character should react to.
+//!
 //! ## Motion Based Animation
 //!
 //! [`TnuaController`](crate::prelude::TnuaController) can also be used to retreive data that can
@@ -118,12 +132,16 @@ mod basis_action_traits;
 pub mod builtins;
 pub mod control_helpers;
 pub mod controller;
+#[cfg(feature = "debug_gizmos")]
+pub mod debug_gizmos;
+mod surface;
 mod util;
 pub use animating_helper::{TnuaAnimatingState, TnuaAnimatingStateDirective};
 pub use basis_action_traits::{
     DynamicAction, DynamicBasis, TnuaAction, TnuaActionContext, TnuaActionInitiationDirective,
     TnuaActionLifecycleDirective, TnuaActionLifecycleStatus, TnuaBasis, TnuaBasisContext,
 };
+pub use surface::TnuaSurface;
 
 pub mod prelude {
     pub use crate::builtins::{TnuaBuiltinJump, TnuaBuiltinWalk};
@@ -0,0 +1,49 @@
+use bevy::prelude::*;
+use bevy_tnua_physics_integration_layer::math::Float;
+
+/// Movement modifiers for surfaces the character can stand on - e.g. ice, mud, or sticky floors.
+///
+/// Add this component to the entity of the ground's collider (the entity that the
+/// [`TnuaProximitySensor`](crate::TnuaProximitySensor) reports hitting). The controller passes it
+/// to the basis and to the action (in [`TnuaBasisContext::surface`](crate::TnuaBasisContext::surface)
+/// and [`TnuaActionContext::surface`](crate::TnuaActionContext::surface)) while the character
+/// stands on that entity. [`TnuaBuiltinWalk`](crate::builtins::TnuaBuiltinWalk) and
+/// [`TnuaBuiltinJump`](crate::builtins::TnuaBuiltinJump) (and the actions that are built on it)
+/// read it automatically, so no changes to the control systems are needed.
+///
+/// All the multipliers default to 1.0, which means the surface behaves like any other ground.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Default)]
+pub struct TnuaSurface {
+    /// Multiplies the [walk acceleration](crate::builtins::TnuaBuiltinWalk::acceleration) when the
+    /// character stops on the surface.
+    ///
+    /// Use low values for slippery surfaces like ice, so that the character will slide before it
+    /// stops.
+    pub friction_multiplier: Float,
+
+    /// Multiplies the [walk acceleration](crate::builtins::TnuaBuiltinWalk::acceleration) when the
+    /// character starts moving or changes direction on the surface.
+    pub acceleration_multiplier: Float,
+
+    /// Multiplies the [desired velocity](crate::builtins::TnuaBuiltinWalk::desired_velocity) of
+    /// the character while it walks on the surface.
+    ///
+    /// Use values lower than 1.0 for surfaces that slow the character down, like mud.
+    pub max_speed_multiplier: Float,
+
+    /// Multiplies the [height](crate::builtins::TnuaBuiltinJump::height) of jumps that start on
+    /// the surface.
+    pub jump_height_multiplier: Float,
+}
+
+impl Default for TnuaSurface {
+    fn default() -> Self {
+        Self {
+            friction_multiplier: 1.0,
+            acceleration_multiplier: 1.0,
+            max_speed_multiplier: 1.0,
+            jump_height_multiplier: 1.0,
+        }
+    }
+}
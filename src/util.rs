@@ -3,9 +3,12 @@ use bevy_tnua_physics_integration_layer::math::{
     AdjustPrecision, Float, Quaternion, Vector2, Vector3,
 };
 
+use crate::TnuaRigidBodyTracker;
+
 pub struct SegmentedJumpInitialVelocityCalculator {
     height: Float,
     kinetic_energy: Float,
+    duration: Float,
 }
 
 impl SegmentedJumpInitialVelocityCalculator {
@@ -13,6 +16,7 @@ impl SegmentedJumpInitialVelocityCalculator {
         Self {
             height: total_height,
             kinetic_energy: 0.0,
+            duration: 0.0,
         }
     }
 
@@ -30,6 +34,7 @@ impl SegmentedJumpInitialVelocityCalculator {
             return self;
         }
 
+        let velocity_before_segment = (2.0 * self.kinetic_energy).sqrt();
         let segment_height = transferred_energy / gravity;
         if self.height < segment_height {
             // This segment will be the last
@@ -39,6 +44,7 @@ impl SegmentedJumpInitialVelocityCalculator {
             self.kinetic_energy += transferred_energy;
             self.height -= segment_height;
         }
+        self.duration += ((2.0 * self.kinetic_energy).sqrt() - velocity_before_segment) / gravity;
 
         self
     }
@@ -46,6 +52,10 @@ impl SegmentedJumpInitialVelocityCalculator {
     pub fn kinetic_energy(&self) -> Float {
         self.kinetic_energy
     }
+
+    pub fn duration(&self) -> Float {
+        self.duration
+    }
 }
 
 pub struct ProjectionPlaneForRotation {
@@ -81,3 +91,55 @@ impl ProjectionPlaneForRotation {
         rotation_to_set_forward.xyz().z
     }
 }
+
+/// Calculate the angular boost for keeping the character standing upright and for turning it so
+/// that its negative Z will face `desired_forward`.
+///
+/// Turning is skipped if `desired_forward` is zero.
+pub fn upright_and_turning_boost(
+    tracker: &TnuaRigidBodyTracker,
+    frame_duration: Float,
+    up: Direction3d,
+    desired_forward: Vector3,
+    tilt_offset_angvel: Float,
+    tilt_offset_angacl: Float,
+    turning_angvel: Float,
+) -> Vector3 {
+    // Tilt
+
+    let torque_to_fix_tilt = {
+        let tilted_up = tracker.rotation.mul_vec3(up.adjust_precision());
+
+        let rotation_required_to_fix_tilt =
+            Quaternion::from_rotation_arc(tilted_up, up.adjust_precision());
+
+        let desired_angvel = (rotation_required_to_fix_tilt.xyz() / frame_duration)
+            .clamp_length_max(tilt_offset_angvel);
+        let angular_velocity_diff = desired_angvel - tracker.angvel;
+        angular_velocity_diff.clamp_length_max(frame_duration * tilt_offset_angacl)
+    };
+
+    // Turning
+
+    let desired_angvel = if 0.0 < desired_forward.length_squared() {
+        let projection = ProjectionPlaneForRotation::from_up_using_default_forward(up);
+        let current_forward = tracker.rotation.mul_vec3(projection.forward);
+        let rotation_along_up_axis =
+            projection.rotation_to_set_forward(current_forward, desired_forward);
+        (rotation_along_up_axis / frame_duration).clamp(-turning_angvel, turning_angvel)
+    } else {
+        0.0
+    };
+
+    // NOTE: This is the regular axis system so we used the configured up.
+    let existing_angvel = tracker.angvel.dot(up.adjust_precision());
+
+    // This is the torque. Should it be clamped by an acceleration? From experimenting with
+    // this I think it's meaningless and only causes bugs.
+    let torque_to_turn = desired_angvel - existing_angvel;
+
+    let existing_turn_torque = torque_to_fix_tilt.dot(up.adjust_precision());
+    let torque_to_turn = torque_to_turn - existing_turn_torque;
+
+    torque_to_fix_tilt + torque_to_turn * up.adjust_precision()
+}
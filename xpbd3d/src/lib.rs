@@ -6,6 +6,7 @@
 //! * Optionally: Add [`TnuaXpbd3dSensorShape`] to the sensor entities. This means the entity of
 //!   the characters controlled by Tnua, but also other things like the entity generated by
 //!   `TnuaCrouchEnforcer`, that can be affected with a closure.
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleLabel};
 use bevy::prelude::*;
 use bevy_tnua_physics_integration_layer::math::AdjustPrecision;
 use bevy_tnua_physics_integration_layer::math::AsF32;
@@ -16,34 +17,65 @@ use bevy_xpbd_3d::prelude::*;
 use bevy_tnua_physics_integration_layer::data_for_backends::TnuaGhostPlatform;
 use bevy_tnua_physics_integration_layer::data_for_backends::TnuaGhostSensor;
 use bevy_tnua_physics_integration_layer::data_for_backends::TnuaToggle;
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaLedgeSensor, TnuaLedgeSensorOutput, TnuaStepSensor, TnuaStepSensorOutput,
+};
 use bevy_tnua_physics_integration_layer::data_for_backends::{
     TnuaMotor, TnuaProximitySensor, TnuaProximitySensorOutput, TnuaRigidBodyTracker,
 };
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaWallSensor, TnuaWallSensorOutput,
+};
+use bevy_tnua_physics_integration_layer::data_for_backends::{
+    TnuaWaterSensor, TnuaWaterSensorOutput, TnuaWaterVolume,
+};
 use bevy_tnua_physics_integration_layer::subservient_sensors::TnuaSubservientSensor;
 use bevy_tnua_physics_integration_layer::TnuaPipelineStages;
 use bevy_tnua_physics_integration_layer::TnuaSystemSet;
 
 /// Add this plugin to use bevy_xpbd_3d as a physics backend.
 ///
-/// This plugin should be used in addition to `TnuaControllerPlugin`.
-pub struct TnuaXpbd3dPlugin;
+/// This plugin should be used in addition to `TnuaControllerPlugin`, and both plugins must use
+/// the same schedule.
+pub struct TnuaXpbd3dPlugin {
+    schedule: InternedScheduleLabel,
+}
+
+impl TnuaXpbd3dPlugin {
+    /// Create the plugin, running its systems in the given schedule.
+    pub fn new(schedule: impl ScheduleLabel) -> Self {
+        Self {
+            schedule: schedule.intern(),
+        }
+    }
+}
+
+impl Default for TnuaXpbd3dPlugin {
+    fn default() -> Self {
+        Self::new(Update)
+    }
+}
 
 impl Plugin for TnuaXpbd3dPlugin {
     fn build(&self, app: &mut App) {
         app.configure_sets(
-            Update,
+            self.schedule,
             TnuaSystemSet.run_if(|physics_time: Res<Time<Physics>>| !physics_time.is_paused()),
         );
         app.add_systems(
-            Update,
+            self.schedule,
             (
                 update_rigid_body_trackers_system,
                 update_proximity_sensors_system,
+                update_water_sensors_system,
+                update_wall_sensors_system,
+                update_ledge_sensors_system,
+                update_step_sensors_system,
             )
                 .in_set(TnuaPipelineStages::Sensors),
         );
         app.add_systems(
-            Update,
+            self.schedule,
             apply_motors_system.in_set(TnuaPipelineStages::Motors),
         );
     }
@@ -81,6 +113,54 @@ fn update_rigid_body_trackers_system(
     }
 }
 
+/// Create a query filter for the sensors of `owner_entity`, and get the collision layers of its
+/// collider that the colliders the sensors detect must interact with.
+fn owner_query_filter(
+    collision_layers_entity: &Query<&CollisionLayers>,
+    owner_entity: Entity,
+) -> (SpatialQueryFilter, CollisionLayers) {
+    let collision_layers = collision_layers_entity
+        .get(owner_entity)
+        .ok()
+        .copied()
+        .unwrap_or_default();
+    (
+        SpatialQueryFilter::from_excluded_entities([owner_entity]),
+        collision_layers,
+    )
+}
+
+type SensorTargetQueryData = (
+    Option<&'static CollisionLayers>,
+    Has<TnuaGhostPlatform>,
+    Has<Sensor>,
+);
+
+/// Create a query filter for the sensors of `owner_entity` that only detect solid colliders (unlike
+/// the water sensor), and the predicate to use with it.
+///
+/// The predicate ignores ghost platforms, sensor colliders, and colliders that the owner does not
+/// interact with.
+fn sensor_query_filter<'a>(
+    collision_layers_entity: &Query<&CollisionLayers>,
+    other_object_query: &'a Query<'a, 'a, SensorTargetQueryData>,
+    owner_entity: Entity,
+) -> (SpatialQueryFilter, impl 'a + Fn(Entity) -> bool) {
+    let (query_filter, collision_layers) =
+        owner_query_filter(collision_layers_entity, owner_entity);
+    let predicate = move |other_entity| {
+        let Ok((entity_collision_layers, entity_is_ghost, entity_is_sensor)) =
+            other_object_query.get(other_entity)
+        else {
+            return false;
+        };
+        !entity_is_ghost
+            && !entity_is_sensor
+            && collision_layers.interacts_with(entity_collision_layers.copied().unwrap_or_default())
+    };
+    (query_filter, predicate)
+}
+
 #[allow(clippy::type_complexity)]
 fn update_proximity_sensors_system(
     spatial_query_pipeline: Res<SpatialQueryPipeline>,
@@ -134,7 +214,8 @@ fn update_proximity_sensors_system(
                 owner_entity
             };
 
-            let collision_layers = collision_layers_entity.get(owner_entity).ok();
+            let (query_filter, collision_layers) =
+                owner_query_filter(&collision_layers_entity, owner_entity);
 
             let mut final_sensor_output = None;
             if let Some(ghost_sensor) = ghost_sensor.as_mut() {
@@ -210,7 +291,6 @@ fn update_proximity_sensors_system(
                 };
 
                 let excluded_by_collision_layers = || {
-                    let collision_layers = collision_layers.copied().unwrap_or_default();
                     let entity_collision_layers =
                         entity_collision_layers.copied().unwrap_or_default();
                     !collision_layers.interacts_with(entity_collision_layers)
@@ -229,7 +309,6 @@ fn update_proximity_sensors_system(
                 }
             };
 
-            let query_filter = SpatialQueryFilter::from_excluded_entities([owner_entity]);
             if let Some(TnuaXpbd3dSensorShape(shape)) = shape {
                 spatial_query_pipeline.shape_hits_callback(
                     shape,
@@ -273,6 +352,195 @@ fn update_proximity_sensors_system(
     );
 }
 
+fn update_water_sensors_system(
+    spatial_query_pipeline: Res<SpatialQueryPipeline>,
+    mut query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut TnuaWaterSensor,
+        Option<&TnuaToggle>,
+    )>,
+    water_volumes_query: Query<(), With<TnuaWaterVolume>>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.translation().adjust_precision()
+            + sensor.cast_range * sensor.up.adjust_precision();
+        sensor.output = spatial_query_pipeline
+            .cast_ray_predicate(
+                cast_origin,
+                -sensor.up,
+                sensor.cast_range,
+                true,
+                SpatialQueryFilter::from_excluded_entities([owner_entity]),
+                &|other_entity| water_volumes_query.contains(other_entity),
+            )
+            .map(|ray_hit_data| TnuaWaterSensorOutput {
+                entity: ray_hit_data.entity,
+                depth: sensor.cast_range - ray_hit_data.time_of_impact,
+            });
+    }
+}
+
+fn update_wall_sensors_system(
+    spatial_query_pipeline: Res<SpatialQueryPipeline>,
+    mut query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut TnuaWallSensor,
+        Option<&TnuaToggle>,
+    )>,
+    collision_layers_entity: Query<&CollisionLayers>,
+    other_object_query: Query<SensorTargetQueryData>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.transform_point(sensor.cast_origin.f32());
+        let cast_direction = sensor.cast_direction;
+        let (query_filter, predicate) =
+            sensor_query_filter(&collision_layers_entity, &other_object_query, owner_entity);
+        sensor.output = spatial_query_pipeline
+            .cast_ray_predicate(
+                cast_origin.adjust_precision(),
+                cast_direction,
+                sensor.cast_range,
+                true,
+                query_filter,
+                &predicate,
+            )
+            .map(|ray_hit_data| TnuaWallSensorOutput {
+                entity: ray_hit_data.entity,
+                proximity: ray_hit_data.time_of_impact,
+                normal: Direction3d::new(ray_hit_data.normal.f32()).unwrap_or(-cast_direction),
+            });
+    }
+}
+
+fn update_ledge_sensors_system(
+    spatial_query_pipeline: Res<SpatialQueryPipeline>,
+    mut query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut TnuaLedgeSensor,
+        Option<&TnuaToggle>,
+    )>,
+    collision_layers_entity: Query<&CollisionLayers>,
+    other_object_query: Query<SensorTargetQueryData>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.transform_point(sensor.cast_origin.f32());
+        let (query_filter, predicate) =
+            sensor_query_filter(&collision_layers_entity, &other_object_query, owner_entity);
+
+        let forward_hit = spatial_query_pipeline.cast_ray_predicate(
+            cast_origin.adjust_precision(),
+            sensor.cast_direction,
+            sensor.reach,
+            true,
+            query_filter.clone(),
+            &predicate,
+        );
+        if forward_hit.is_some() {
+            sensor.output = None;
+            continue;
+        }
+
+        let up = sensor.up;
+        let probe_origin = cast_origin.adjust_precision()
+            + sensor.reach * sensor.cast_direction.adjust_precision();
+        sensor.output = spatial_query_pipeline
+            .cast_ray_predicate(
+                probe_origin,
+                -up,
+                sensor.probe_depth,
+                true,
+                query_filter.clone(),
+                &predicate,
+            )
+            .filter(|ray_hit_data| 0.0 < ray_hit_data.time_of_impact)
+            .map(|ray_hit_data| {
+                let proximity = ray_hit_data.time_of_impact;
+                let headroom = if proximity < sensor.headroom_range {
+                    spatial_query_pipeline
+                        .cast_ray_predicate(
+                            probe_origin,
+                            up,
+                            sensor.headroom_range - proximity,
+                            true,
+                            query_filter.clone(),
+                            &predicate,
+                        )
+                        .map_or(Float::INFINITY, |ceiling| {
+                            proximity + ceiling.time_of_impact
+                        })
+                } else {
+                    Float::INFINITY
+                };
+                TnuaLedgeSensorOutput {
+                    entity: ray_hit_data.entity,
+                    point: probe_origin - proximity * up.adjust_precision(),
+                    normal: Direction3d::new(ray_hit_data.normal.f32()).unwrap_or(up),
+                    headroom,
+                }
+            });
+    }
+}
+
+fn update_step_sensors_system(
+    spatial_query_pipeline: Res<SpatialQueryPipeline>,
+    mut query: Query<(
+        Entity,
+        &GlobalTransform,
+        &mut TnuaStepSensor,
+        Option<&TnuaToggle>,
+    )>,
+    collision_layers_entity: Query<&CollisionLayers>,
+    other_object_query: Query<SensorTargetQueryData>,
+) {
+    for (owner_entity, transform, mut sensor, tnua_toggle) in query.iter_mut() {
+        match tnua_toggle.copied().unwrap_or_default() {
+            TnuaToggle::Disabled => continue,
+            TnuaToggle::SenseOnly => {}
+            TnuaToggle::Enabled => {}
+        }
+        let cast_origin = transform.transform_point(sensor.cast_origin.f32());
+        let (query_filter, predicate) =
+            sensor_query_filter(&collision_layers_entity, &other_object_query, owner_entity);
+
+        let up = sensor.up;
+        let probe_origin = cast_origin.adjust_precision()
+            + sensor.reach * sensor.cast_direction.adjust_precision();
+        sensor.output = spatial_query_pipeline
+            .cast_ray_predicate(
+                probe_origin,
+                -up,
+                sensor.probe_depth,
+                true,
+                query_filter,
+                &predicate,
+            )
+            .filter(|ray_hit_data| 0.0 < ray_hit_data.time_of_impact)
+            .map(|ray_hit_data| TnuaStepSensorOutput {
+                entity: ray_hit_data.entity,
+                point: probe_origin - ray_hit_data.time_of_impact * up.adjust_precision(),
+                normal: Direction3d::new(ray_hit_data.normal.f32()).unwrap_or(up),
+            });
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn apply_motors_system(
     mut query: Query<(